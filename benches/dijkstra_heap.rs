@@ -0,0 +1,78 @@
+//! Compares `Graph::dijkstra_with_arity` heap arities (2, 4, 8) on generated
+//! sparse and dense graphs, to justify the default arity used by
+//! `Graph::dijkstra`/`Graph::astar`.
+
+use aoc::dijkstra::Graph;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// A grid-shaped graph with `size * size` nodes, where each node is connected
+/// to its 4 orthogonal neighbors (sparse) or additionally to its 4 diagonal
+/// neighbors (dense).
+struct GeneratedGraph {
+    size: usize,
+    dense: bool,
+}
+
+impl Graph for GeneratedGraph {
+    type Node = (usize, usize);
+    type Distance = u32;
+
+    fn adjacent(&self, &(x, y): &Self::Node) -> Vec<Self::Node> {
+        let mut offsets = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
+        if self.dense {
+            offsets.extend([(-1, -1), (-1, 1), (1, -1), (1, 1)]);
+        }
+
+        offsets
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < self.size && ny < self.size).then_some((nx, ny))
+            })
+            .collect()
+    }
+
+    fn edge(&self, _from: &Self::Node, _to: &Self::Node) -> Self::Distance {
+        1
+    }
+}
+
+fn bench_arity(c: &mut Criterion, name: &str, graph: &GeneratedGraph) {
+    let from = (0, 0);
+    let to = (graph.size - 1, graph.size - 1);
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("arity=2", |b| {
+        b.iter(|| black_box(graph.dijkstra_with_arity::<2>(from, to)))
+    });
+    group.bench_function("arity=4", |b| {
+        b.iter(|| black_box(graph.dijkstra_with_arity::<4>(from, to)))
+    });
+    group.bench_function("arity=8", |b| {
+        b.iter(|| black_box(graph.dijkstra_with_arity::<8>(from, to)))
+    });
+    group.finish();
+}
+
+fn dijkstra_heap_arity(c: &mut Criterion) {
+    bench_arity(
+        c,
+        "dijkstra_heap/sparse",
+        &GeneratedGraph {
+            size: 150,
+            dense: false,
+        },
+    );
+    bench_arity(
+        c,
+        "dijkstra_heap/dense",
+        &GeneratedGraph {
+            size: 150,
+            dense: true,
+        },
+    );
+}
+
+criterion_group!(benches, dijkstra_heap_arity);
+criterion_main!(benches);
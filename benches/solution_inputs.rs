@@ -0,0 +1,55 @@
+//! [`Solution`] counterpart to `solver_inputs`: measures `part1`/`part2`
+//! time for each [`Solution`] day against its embedded real input (requires
+//! the `embedded-input` feature).
+//!
+//! Day 6 is of particular interest here -- `part2` runs the single giant
+//! "bad kerning" race through the same `u128` closed form as `part1`'s small
+//! races, so this is what would catch a regression back to something
+//! `O(time)`.
+
+use aoc::{
+    embed_input,
+    solution::Solution,
+    solutions::day06::{Format, Race},
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_solution<S: Solution>(c: &mut Criterion, name: &str, input: &str) {
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("part1", |b| b.iter(|| black_box(S::part1(input)).unwrap()));
+    group.bench_function("part2", |b| b.iter(|| black_box(S::part2(input)).unwrap()));
+
+    group.finish();
+}
+
+/// Compares Day 6 part 2's `O(1)` closed form against the `O(time)` brute
+/// force it's checked against in `race.rs`'s tests, on the one giant
+/// "bad kerning" race real input produces.
+fn bench_day06_closed_form_vs_bruteforce(c: &mut Criterion, input: &str) {
+    let race = Race::parse_format(input, Format::Single)
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let mut group = c.benchmark_group("day06/part2_margin");
+    group.bench_function("closed_form", |b| b.iter(|| black_box(race.margin())));
+    group.bench_function("bruteforce", |b| {
+        b.iter(|| black_box(race.margin_bruteforce()))
+    });
+    group.finish();
+}
+
+fn solution_inputs(c: &mut Criterion) {
+    bench_solution::<aoc::solutions::day01::Day01>(c, "day01", embed_input!(1));
+    bench_solution::<aoc::solutions::day02::Day02>(c, "day02", embed_input!(2));
+    bench_solution::<aoc::solutions::day04::Day04>(c, "day04", embed_input!(4));
+    bench_solution::<aoc::solutions::day06::Day06>(c, "day06", embed_input!(6));
+    bench_solution::<aoc::solutions::day13::Day13>(c, "day13", embed_input!(13));
+
+    bench_day06_closed_form_vs_bruteforce(c, embed_input!(6));
+}
+
+criterion_group!(benches, solution_inputs);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+//! Measures parse/part1/part2 time for each [`Solver`] day against its
+//! embedded real input (requires the `embedded-input` feature), so
+//! performance-motivated redesigns -- the Day 3 `Schematic` adjacency index,
+//! the Day 4 copy-count ring buffer -- can be compared quantitatively
+//! instead of by feel.
+
+use aoc::{embed_input, solver::Solver};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_solver<S: Solver>(c: &mut Criterion, name: &str, input: &str) {
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+
+    let solver = S::parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| black_box(solver.part1())));
+    group.bench_function("part2", |b| b.iter(|| black_box(solver.part2())));
+
+    group.finish();
+}
+
+fn solver_inputs(c: &mut Criterion) {
+    bench_solver::<aoc::solutions::day03::Day03>(c, "day03", embed_input!(3));
+    bench_solver::<aoc::solutions::day04::Day04>(c, "day04", embed_input!(4));
+    bench_solver::<aoc::solutions::day18::Day18>(c, "day18", embed_input!(18));
+}
+
+criterion_group!(benches, solver_inputs);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 //! Comand line argument parsing infrastructure.
 
-use std::path::PathBuf;
+use std::{ops::RangeInclusive, path::PathBuf};
 
 use clap::Parser;
 
@@ -26,23 +26,106 @@ static HELP_TEMPLATE: &str = "{bin} {version}\n{author-with-newline}{about-secti
     help_template = HELP_TEMPLATE
 )]
 pub struct Cli {
-    /// Specify which solution to run (e.g., `day<N>` or a number 1-25)
-    #[arg(value_parser = parse_solution_day)]
-    pub day: i32,
-    /// Input file for the solution (default: `data/day<N>.txt`)
+    /// Specify which solution(s) to run (e.g., `day<N>`, a number 1-25, or a
+    /// range like `1..=25`); required unless `--all` is given
+    #[arg(value_parser = parse_day_spec, required_unless_present = "all")]
+    pub day: Option<DaySpec>,
+    /// Input file for the solution (default: `data/day<N>.txt`; only valid
+    /// when `day` selects a single solution)
     #[arg(short, long)]
     pub input: Option<PathBuf>,
+    /// Output format for answers
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+    /// Run every day with a timed solution and print a Markdown table of
+    /// each part's duration, plus a grand total
+    #[arg(long, conflicts_with = "day")]
+    pub all: bool,
 }
 
-fn parse_solution_day(value: &str) -> Result<i32, String> {
-    let s = value.strip_prefix("day").unwrap_or(value).trim();
-    if let Ok(num) = s.parse::<i32>() {
-        if (1..=25).contains(&num) {
-            return Ok(num);
-        } else {
-            return Err(format!("{} is not in the range 1-25", num));
+/// How a day's answers are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// The current human-readable `Part N: value (elapsed)` lines.
+    Text,
+    /// One JSON object per part, suitable for piping to `jq`/nushell.
+    Json,
+}
+
+/// A day, or inclusive range of days, selected on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySpec(RangeInclusive<i32>);
+
+impl DaySpec {
+    /// Iterates the selected days in ascending order.
+    pub fn days(&self) -> RangeInclusive<i32> {
+        self.0.clone()
+    }
+
+    /// Returns `true` if this selects more than one day.
+    pub fn is_range(&self) -> bool {
+        self.0.start() != self.0.end()
+    }
+}
+
+fn parse_day_spec(value: &str) -> Result<DaySpec, String> {
+    if let Some((lo, hi)) = value.split_once("..=").or_else(|| value.split_once("..")) {
+        let lo = parse_single_day(lo)?;
+        let hi = parse_single_day(hi)?;
+        if lo > hi {
+            return Err(format!("range start {lo} is after range end {hi}"));
         }
+
+        return Ok(DaySpec(lo..=hi));
     }
 
-    Err("argument is not a valid solution name".into())
+    let day = parse_single_day(value)?;
+    Ok(DaySpec(day..=day))
+}
+
+fn parse_single_day(value: &str) -> Result<i32, String> {
+    let value = value.trim();
+    let s = value.strip_prefix("day").unwrap_or(value);
+    match s.parse::<i32>() {
+        Ok(num) if (1..=25).contains(&num) => Ok(num),
+        Ok(num) => Err(format!("{} is not in the range 1-25", num)),
+        Err(_) => Err("argument is not a valid solution name".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_day_spec_single() {
+        for value in ["4", "day4", " day4 "] {
+            assert_eq!(parse_day_spec(value).unwrap(), DaySpec(4..=4));
+        }
+    }
+
+    #[test]
+    fn parse_day_spec_range() {
+        assert_eq!(parse_day_spec("1..=25").unwrap(), DaySpec(1..=25));
+        assert_eq!(parse_day_spec("1..25").unwrap(), DaySpec(1..=25));
+        assert!(!DaySpec(4..=4).is_range());
+        assert!(DaySpec(1..=25).is_range());
+    }
+
+    #[test]
+    fn parse_day_spec_rejects_out_of_range() {
+        assert!(parse_day_spec("26").is_err());
+        assert!(parse_day_spec("0").is_err());
+        assert!(parse_day_spec("1..=26").is_err());
+    }
+
+    #[test]
+    fn parse_day_spec_rejects_backwards_range() {
+        assert!(parse_day_spec("10..=2").is_err());
+    }
+
+    #[test]
+    fn parse_day_spec_rejects_garbage() {
+        assert!(parse_day_spec("banana").is_err());
+    }
 }
@@ -0,0 +1,189 @@
+//! Three-dimensional coordinates and the "exterior surface area" voxel
+//! primitive: given a set of unit cubes, counts only the faces reachable
+//! from outside the cubes, excluding any air pockets fully enclosed by them.
+
+use std::collections::{HashSet, VecDeque};
+
+/// A point in 3D integer space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coord3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Coord3 {
+    /// Creates a new [`Coord3`] from the given `(x, y, z)`.
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the coordinate one unit over in the given `dir`.
+    pub fn step(&self, dir: Direction3) -> Self {
+        let (dx, dy, dz) = dir.offset();
+        Self::new(self.x + dx, self.y + dy, self.z + dz)
+    }
+
+    /// Returns the six face-adjacent neighbors of this coordinate.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Self> + '_ {
+        Direction3::ALL.into_iter().map(|dir| self.step(dir))
+    }
+}
+
+impl From<(i64, i64, i64)> for Coord3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+/// The six face-normal directions in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    East,
+    West,
+    North,
+    South,
+    Up,
+    Down,
+}
+
+impl Direction3 {
+    /// All six [`Direction3`] variants.
+    pub const ALL: [Self; 6] = [
+        Self::East,
+        Self::West,
+        Self::North,
+        Self::South,
+        Self::Up,
+        Self::Down,
+    ];
+
+    /// The unit `(dx, dy, dz)` offset this direction steps by.
+    fn offset(self) -> (i64, i64, i64) {
+        match self {
+            Self::East => (1, 0, 0),
+            Self::West => (-1, 0, 0),
+            Self::North => (0, 1, 0),
+            Self::South => (0, -1, 0),
+            Self::Up => (0, 0, 1),
+            Self::Down => (0, 0, -1),
+        }
+    }
+}
+
+/// Computes the exterior surface area of the given set of unit `cubes`.
+///
+/// Expands the cubes' axis-aligned bounding box by one unit in every
+/// direction, then flood-fills the "air" from a corner of that expanded box
+/// using six-neighbor connectivity. Every face of a visited air cell that
+/// borders a cube counts toward the surface area; since the flood fill can
+/// never reach air fully enclosed by cubes, those interior faces are
+/// correctly excluded (unlike a naive "sum each cube's exposed faces"
+/// approach, which would count interior pockets too).
+///
+/// Returns `0` if `cubes` is empty.
+pub fn exterior_surface_area(cubes: &HashSet<Coord3>) -> usize {
+    let Some(&first) = cubes.iter().next() else {
+        return 0;
+    };
+
+    let (mut min, mut max) = (first, first);
+    for c in cubes {
+        min.x = min.x.min(c.x);
+        min.y = min.y.min(c.y);
+        min.z = min.z.min(c.z);
+        max.x = max.x.max(c.x);
+        max.y = max.y.max(c.y);
+        max.z = max.z.max(c.z);
+    }
+    let min = Coord3::new(min.x - 1, min.y - 1, min.z - 1);
+    let max = Coord3::new(max.x + 1, max.y + 1, max.z + 1);
+
+    let in_bounds = |c: &Coord3| {
+        (min.x..=max.x).contains(&c.x)
+            && (min.y..=max.y).contains(&c.y)
+            && (min.z..=max.z).contains(&c.z)
+    };
+
+    let mut surface = 0;
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(min);
+    visited.insert(min);
+
+    while let Some(pos) = queue.pop_front() {
+        for next in pos.neighbors6() {
+            if !in_bounds(&next) {
+                continue;
+            }
+            if cubes.contains(&next) {
+                surface += 1;
+            } else if visited.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    surface
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the AoC 2022 Day 18 example droplet: 13 cubes with a single
+    // fully-enclosed interior air pocket at (2, 2, 5).
+    fn example_cubes() -> HashSet<Coord3> {
+        [
+            (2, 2, 2),
+            (1, 2, 2),
+            (3, 2, 2),
+            (2, 1, 2),
+            (2, 3, 2),
+            (2, 2, 1),
+            (2, 2, 3),
+            (2, 2, 4),
+            (2, 2, 6),
+            (1, 2, 5),
+            (3, 2, 5),
+            (2, 1, 5),
+            (2, 3, 5),
+        ]
+        .into_iter()
+        .map(Coord3::from)
+        .collect()
+    }
+
+    #[test]
+    fn step_moves_one_unit() {
+        let p = Coord3::new(0, 0, 0);
+        assert_eq!(p.step(Direction3::East), Coord3::new(1, 0, 0));
+        assert_eq!(p.step(Direction3::Up), Coord3::new(0, 0, 1));
+    }
+
+    #[test]
+    fn neighbors6_has_six_distinct_neighbors() {
+        let p = Coord3::new(0, 0, 0);
+        let neighbors: HashSet<_> = p.neighbors6().collect();
+        assert_eq!(neighbors.len(), 6);
+        assert!(!neighbors.contains(&p));
+    }
+
+    #[test]
+    fn exterior_surface_area_excludes_interior_pocket() {
+        // total surface area (including the interior pocket) is 64; the
+        // exterior-only count excludes the 6 faces around the pocket.
+        assert_eq!(exterior_surface_area(&example_cubes()), 58);
+    }
+
+    #[test]
+    fn exterior_surface_area_single_cube() {
+        let cubes: HashSet<_> = [Coord3::new(0, 0, 0)].into_iter().collect();
+        assert_eq!(exterior_surface_area(&cubes), 6);
+    }
+
+    #[test]
+    fn exterior_surface_area_empty_is_zero() {
+        assert_eq!(exterior_surface_area(&HashSet::new()), 0);
+    }
+}
@@ -1,5 +1,9 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::bail;
+
 /// Represents a [cardinal direction](https://en.wikipedia.org/wiki/Cardinal_direction).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Direction {
     /// Up, forward, or an azimuth of 0°
     North,
@@ -11,7 +15,134 @@ pub enum Direction {
     West,
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+impl Direction {
+    /// All four variants, in clockwise order starting from [`Direction::North`].
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Returns an iterator over all four variants, in clockwise order
+    /// starting from [`Direction::North`].
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the direction facing the opposite way (a 180° rotation).
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Returns the direction after a 90° clockwise rotation.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// Returns the direction after a 90° counter-clockwise rotation.
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+
+    /// Reflects this direction off a mirror of the given orientation, as
+    /// used by beam-bouncing grid puzzles.
+    ///
+    /// `mirror` must be `'/'` or `'\\'`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mirror` is not `'/'` or `'\\'`.
+    pub const fn reflect(self, mirror: char) -> Self {
+        match (mirror, self) {
+            ('/', Self::North) | ('\\', Self::South) => Self::East,
+            ('/', Self::East) | ('\\', Self::West) => Self::North,
+            ('/', Self::South) | ('\\', Self::North) => Self::West,
+            ('/', Self::West) | ('\\', Self::East) => Self::South,
+            _ => panic!("mirror must be '/' or '\\\\'"),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::North => "North",
+            Self::East => "East",
+            Self::South => "South",
+            Self::West => "West",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" | "North" => Ok(Self::North),
+            "E" | "East" => Ok(Self::East),
+            "S" | "South" => Ok(Self::South),
+            "W" | "West" => Ok(Self::West),
+            _ => bail!("invalid direction `{s}`"),
+        }
+    }
+}
+
+/// Represents an [ordinal (intercardinal) direction](https://en.wikipedia.org/wiki/Points_of_the_compass#Intercardinal_directions),
+/// i.e. a diagonal combining two [`Direction`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Diagonal {
+    /// Up and to the right, or an azimuth of 45°
+    NorthEast,
+    /// Down and to the right, or an azimuth of 135°
+    SouthEast,
+    /// Down and to the left, or an azimuth of 225°
+    SouthWest,
+    /// Up and to the left, or an azimuth of 315°
+    NorthWest,
+}
+
+/// A cardinal or ordinal heading between two coordinates, as classified by
+/// [`Coordinate::direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Heading {
+    /// An axis-aligned heading.
+    Cardinal(Direction),
+    /// A diagonal heading.
+    Ordinal(Diagonal),
+}
+
+impl Heading {
+    /// Returns the [`Direction`], if this heading is [`Heading::Cardinal`].
+    pub fn as_cardinal(&self) -> Option<Direction> {
+        match self {
+            Heading::Cardinal(dir) => Some(*dir),
+            Heading::Ordinal(_) => None,
+        }
+    }
+
+    /// Returns the [`Diagonal`], if this heading is [`Heading::Ordinal`].
+    pub fn as_ordinal(&self) -> Option<Diagonal> {
+        match self {
+            Heading::Cardinal(_) => None,
+            Heading::Ordinal(diag) => Some(*diag),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Coordinate {
     pub x: usize,
     pub y: usize,
@@ -96,35 +227,94 @@ impl Coordinate {
         }
     }
 
-    /// Returns the [`Direction`] pointed to by the 2D vector formed with
-    /// this coordinate and the `to` position.
+    /// Returns the coordinate pointed to by the given diagonal `dir`,
+    /// combining two axis moves.
     ///
-    /// Returns [`None`] if the coordinates are not aligned in a cardinal
-    /// direction.
-    pub fn direction<C: Into<Self>>(&self, to: C) -> Option<Direction> {
+    /// Returns `None` if either axis move is out of bounds (mirroring
+    /// [`Coordinate::by_direction`]).
+    pub fn by_diagonal(&self, dir: Diagonal) -> Option<Self> {
+        let (ns, ew) = match dir {
+            Diagonal::NorthEast => (self.north()?, self.east()?),
+            Diagonal::SouthEast => (self.south()?, self.east()?),
+            Diagonal::SouthWest => (self.south()?, self.west()?),
+            Diagonal::NorthWest => (self.north()?, self.west()?),
+        };
+
+        Some(Self::new(ew.x, ns.y))
+    }
+
+    /// Returns the [`Heading`] (cardinal or ordinal) pointed to by the 2D
+    /// vector formed with this coordinate and the `to` position.
+    ///
+    /// Returns [`None`] if the coordinates are equal, or if they are not
+    /// aligned along a cardinal or 45° diagonal axis.
+    pub fn direction<C: Into<Self>>(&self, to: C) -> Option<Heading> {
         let to: Coordinate = to.into();
         let dx = i64::try_from(to.x).ok()? - i64::try_from(self.x).ok()?;
         let dy = i64::try_from(to.y).ok()? - i64::try_from(self.y).ok()?;
 
         if dx != 0 && dy != 0 {
-            return None;
+            if dx.abs() != dy.abs() {
+                return None;
+            }
+
+            let diagonal = match (dx.signum(), dy.signum()) {
+                (1, -1) => Diagonal::NorthEast,
+                (1, 1) => Diagonal::SouthEast,
+                (-1, 1) => Diagonal::SouthWest,
+                (-1, -1) => Diagonal::NorthWest,
+                _ => unreachable!("signum is -1 or 1 here, since dx and dy are both nonzero"),
+            };
+            return Some(Heading::Ordinal(diagonal));
         }
 
         if dy > 0 {
-            return Some(Direction::South);
+            return Some(Heading::Cardinal(Direction::South));
         }
         if dy < 0 {
-            return Some(Direction::North);
+            return Some(Heading::Cardinal(Direction::North));
         }
         if dx > 0 {
-            return Some(Direction::East);
+            return Some(Heading::Cardinal(Direction::East));
         }
         if dx < 0 {
-            return Some(Direction::West);
+            return Some(Heading::Cardinal(Direction::West));
         }
 
         None
     }
+
+    /// Returns an iterator over the (up to 4) orthogonally adjacent
+    /// coordinates that don't underflow, in [`Direction::North`],
+    /// [`Direction::East`], [`Direction::South`], [`Direction::West`] order.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Self> + '_ {
+        [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+        .into_iter()
+        .filter_map(move |dir| self.by_direction(dir))
+    }
+
+    /// Returns an iterator over the (up to 8) orthogonally and diagonally
+    /// adjacent coordinates that don't underflow: the same coordinates as
+    /// [`Coordinate::neighbors4`], followed by the diagonal neighbors in
+    /// [`Diagonal::NorthEast`], [`Diagonal::SouthEast`],
+    /// [`Diagonal::SouthWest`], [`Diagonal::NorthWest`] order.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors4().chain(
+            [
+                Diagonal::NorthEast,
+                Diagonal::SouthEast,
+                Diagonal::SouthWest,
+                Diagonal::NorthWest,
+            ]
+            .into_iter()
+            .filter_map(move |dir| self.by_diagonal(dir)),
+        )
+    }
 }
 
 impl From<&Coordinate> for Coordinate {
@@ -147,3 +337,156 @@ impl From<(usize, usize)> for Coordinate {
         Self::from(&value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_opposite_and_turns() {
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right().turn_right(), Direction::East);
+    }
+
+    #[test]
+    fn direction_reflect() {
+        assert_eq!(Direction::North.reflect('/'), Direction::East);
+        assert_eq!(Direction::East.reflect('/'), Direction::North);
+        assert_eq!(Direction::North.reflect('\\'), Direction::West);
+        assert_eq!(Direction::West.reflect('\\'), Direction::North);
+    }
+
+    #[test]
+    #[should_panic]
+    fn direction_reflect_panics_on_invalid_mirror() {
+        Direction::North.reflect('x');
+    }
+
+    #[test]
+    fn direction_iter_yields_all_variants() {
+        let dirs: Vec<_> = Direction::iter().collect();
+        assert_eq!(
+            dirs,
+            vec![
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West
+            ]
+        );
+    }
+
+    #[test]
+    fn direction_from_str_and_display() {
+        for (s, dir) in [
+            ("N", Direction::North),
+            ("North", Direction::North),
+            ("E", Direction::East),
+            ("S", Direction::South),
+            ("W", Direction::West),
+        ] {
+            assert_eq!(Direction::from_str(s).unwrap(), dir);
+        }
+        assert!(Direction::from_str("Q").is_err());
+        assert_eq!(Direction::North.to_string(), "North");
+    }
+
+    #[test]
+    fn by_diagonal_underflows_at_origin() {
+        let origin = Coordinate::new(0, 0);
+
+        assert_eq!(origin.by_diagonal(Diagonal::NorthEast), None);
+        assert_eq!(origin.by_diagonal(Diagonal::NorthWest), None);
+        assert_eq!(origin.by_diagonal(Diagonal::SouthWest), None);
+        assert_eq!(
+            origin.by_diagonal(Diagonal::SouthEast),
+            Some(Coordinate::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn direction_classifies_cardinals() {
+        let origin = Coordinate::new(1, 1);
+
+        assert_eq!(
+            origin.direction((1, 0)),
+            Some(Heading::Cardinal(Direction::North))
+        );
+        assert_eq!(
+            origin.direction((2, 1)),
+            Some(Heading::Cardinal(Direction::East))
+        );
+        assert_eq!(
+            origin.direction((1, 2)),
+            Some(Heading::Cardinal(Direction::South))
+        );
+        assert_eq!(
+            origin.direction((0, 1)),
+            Some(Heading::Cardinal(Direction::West))
+        );
+    }
+
+    #[test]
+    fn direction_classifies_diagonals() {
+        let origin = Coordinate::new(1, 1);
+
+        assert_eq!(
+            origin.direction((2, 0)),
+            Some(Heading::Ordinal(Diagonal::NorthEast))
+        );
+        assert_eq!(
+            origin.direction((2, 2)),
+            Some(Heading::Ordinal(Diagonal::SouthEast))
+        );
+        assert_eq!(
+            origin.direction((0, 2)),
+            Some(Heading::Ordinal(Diagonal::SouthWest))
+        );
+        assert_eq!(
+            origin.direction((0, 0)),
+            Some(Heading::Ordinal(Diagonal::NorthWest))
+        );
+    }
+
+    #[test]
+    fn direction_is_none_for_unaligned_or_equal() {
+        let origin = Coordinate::new(1, 1);
+
+        assert_eq!(origin.direction((1, 1)), None);
+        assert_eq!(origin.direction((3, 2)), None);
+    }
+
+    #[test]
+    fn neighbors4_excludes_out_of_bounds() {
+        let origin = Coordinate::new(0, 0);
+        let neighbors: Vec<_> = origin.neighbors4().collect();
+
+        assert_eq!(
+            neighbors,
+            vec![Coordinate::new(1, 0), Coordinate::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn neighbors8_interior_has_all_eight() {
+        let center = Coordinate::new(1, 1);
+        assert_eq!(center.neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn neighbors8_corner_excludes_underflowing() {
+        let origin = Coordinate::new(0, 0);
+        let neighbors: Vec<_> = origin.neighbors8().collect();
+
+        assert_eq!(
+            neighbors,
+            vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,465 @@
+//! A directional cursor for walking a [`Grid`], with pluggable behavior for
+//! what happens at the border: stop, toroidal wrapping, or treating the grid
+//! as an unfolded cube net.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Coordinate, Grid};
+
+/// A direction of travel for a [`Cursor`].
+///
+/// This is distinct from [`crate::Direction`]'s compass headings, since a
+/// cursor's "up"/"down"/"left"/"right" are relative to the grid's rows and
+/// columns rather than a cardinal direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Facing {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Facing {
+    /// All four variants.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// Returns the facing after a 90° clockwise turn.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Returns the facing after a 90° counter-clockwise turn.
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    /// Returns the facing after a 180° turn.
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Returns the `(dx, dy)` this facing steps by.
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+/// A 3D unit vector, always axis-aligned (one component is `±1`, the rest
+/// `0`), used by [`CubeNet`] to track each face's orientation in space.
+type Vec3 = (i8, i8, i8);
+
+fn neg((x, y, z): Vec3) -> Vec3 {
+    (-x, -y, -z)
+}
+
+/// A face's orientation relative to the root face of a [`CubeNet`], tracked
+/// as the 3D unit vectors its local right, down, and normal axes point
+/// along (the normal points toward the side the net folds up onto, i.e.
+/// into the cube).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Orientation {
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+impl Orientation {
+    const ROOT: Self = Self {
+        right: (1, 0, 0),
+        down: (0, 1, 0),
+        normal: (0, 0, 1),
+    };
+
+    /// Returns this face's 3D axis vector for a local `facing`.
+    fn vector(self, facing: Facing) -> Vec3 {
+        match facing {
+            Facing::Right => self.right,
+            Facing::Left => neg(self.right),
+            Facing::Down => self.down,
+            Facing::Up => neg(self.down),
+        }
+    }
+
+    /// Returns the orientation of the net-adjacent face reached by folding
+    /// this face's edge in the given `facing` upward into a cube, hinging
+    /// on the edge perpendicular to that direction.
+    fn fold(self, facing: Facing) -> Self {
+        match facing {
+            Facing::Right => Self {
+                right: self.normal,
+                down: self.down,
+                normal: neg(self.right),
+            },
+            Facing::Left => Self {
+                right: neg(self.normal),
+                down: self.down,
+                normal: self.right,
+            },
+            Facing::Down => Self {
+                right: self.right,
+                down: self.normal,
+                normal: neg(self.down),
+            },
+            Facing::Up => Self {
+                right: self.right,
+                down: neg(self.normal),
+                normal: self.down,
+            },
+        }
+    }
+}
+
+/// A precomputed mapping of a [`Grid`]'s unfolded cube net into the face
+/// adjacency and rotation needed to walk off one face and land correctly on
+/// another, as used by [`EdgePolicy::CubeFold`].
+///
+/// Build with [`CubeNet::detect`], which infers the face size from the
+/// number of non-blank cells, then BFS's the net to assign every face an
+/// orientation (tracked as 3D right/down/normal axis vectors) relative to
+/// an arbitrarily chosen root face.
+#[derive(Debug, Clone)]
+pub struct CubeNet {
+    face_size: usize,
+    faces: HashMap<(usize, usize), Orientation>,
+    by_normal: HashMap<Vec3, (usize, usize)>,
+}
+
+impl CubeNet {
+    /// Detects the cube net embedded in `grid`, where `is_blank` identifies
+    /// cells that aren't part of any face.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `grid`'s non-blank cells don't form a valid six-faced cube
+    /// net tiled in square, `grid.width()`/`grid.height()`-aligned blocks.
+    pub fn detect<T>(grid: &Grid<T>, is_blank: impl Fn(&T) -> bool) -> Self {
+        let non_blank = grid.iter().filter(|v| !is_blank(v)).count();
+        let face_area = non_blank / 6;
+        let face_size = (1..=face_area).find(|s| s * s == face_area).expect(
+            "non-blank cell count must be six times a perfect square to form a cube net",
+        );
+
+        let block_cols = grid.width() / face_size;
+        let block_rows = grid.height() / face_size;
+        let is_face = |bx: usize, by: usize| !is_blank(&grid[Coordinate::new(bx * face_size, by * face_size)]);
+
+        let start = (0..block_rows)
+            .flat_map(|by| (0..block_cols).map(move |bx| (bx, by)))
+            .find(|&(bx, by)| is_face(bx, by))
+            .expect("cube net must contain at least one face");
+
+        let mut faces = HashMap::new();
+        faces.insert(start, Orientation::ROOT);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some((bx, by)) = queue.pop_front() {
+            let ori = faces[&(bx, by)];
+            for facing in Facing::ALL {
+                let (dx, dy) = facing.offset();
+                let nbx = bx as isize + dx;
+                let nby = by as isize + dy;
+                if nbx < 0 || nby < 0 || nbx as usize >= block_cols || nby as usize >= block_rows {
+                    continue;
+                }
+                let next = (nbx as usize, nby as usize);
+                if is_face(next.0, next.1) && !faces.contains_key(&next) {
+                    faces.insert(next, ori.fold(facing));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        assert_eq!(faces.len(), 6, "cube net must have exactly six faces");
+        let by_normal = faces.iter().map(|(&block, ori)| (ori.normal, block)).collect();
+
+        Self {
+            face_size,
+            faces,
+            by_normal,
+        }
+    }
+
+    /// Steps from `pos` in the given `facing`, folding across cube edges as
+    /// needed, and returns the new position and facing.
+    fn step(&self, pos: Coordinate, facing: Facing) -> (Coordinate, Facing) {
+        let s = self.face_size;
+        let (bx, by) = (pos.x / s, pos.y / s);
+        let (lx, ly) = (pos.x % s, pos.y % s);
+        let ori = self.faces[&(bx, by)];
+
+        let (dx, dy) = facing.offset();
+        let (nlx, nly) = (lx as isize + dx, ly as isize + dy);
+        if (0..s as isize).contains(&nlx) && (0..s as isize).contains(&nly) {
+            let pos = Coordinate::new(bx * s + nlx as usize, by * s + nly as usize);
+            return (pos, facing);
+        }
+
+        let dir3d = ori.vector(facing);
+        let target_block = self.by_normal[&neg(dir3d)];
+        let tori = self.faces[&target_block];
+
+        let new_facing = Facing::ALL
+            .into_iter()
+            .find(|&f| tori.vector(f) == ori.normal)
+            .expect("every cube face has a neighbor across each edge");
+
+        let (preserved, edge_vec) = match facing {
+            Facing::Left | Facing::Right => (ly, ori.down),
+            Facing::Up | Facing::Down => (lx, ori.right),
+        };
+        let mapped = if tori.right == edge_vec || tori.down == edge_vec {
+            preserved
+        } else {
+            s - 1 - preserved
+        };
+        let on_right_axis = tori.right == edge_vec || tori.right == neg(edge_vec);
+
+        let (new_lx, new_ly) = match new_facing {
+            Facing::Right => (0, mapped),
+            Facing::Left => (s - 1, mapped),
+            Facing::Down => (mapped, 0),
+            Facing::Up => (mapped, s - 1),
+        };
+        // `mapped` always lands on whichever local axis the entry facing
+        // didn't pin down, since edge_vec is orthogonal to the new facing's
+        // own 3D vector by construction; this assertion documents that
+        // invariant rather than changes behavior.
+        debug_assert_eq!(
+            on_right_axis,
+            matches!(new_facing, Facing::Up | Facing::Down),
+            "edge-preserved coordinate must land on the axis the entry facing didn't pin"
+        );
+
+        let (tbx, tby) = target_block;
+        (Coordinate::new(tbx * s + new_lx, tby * s + new_ly), new_facing)
+    }
+}
+
+/// How a [`Cursor`] behaves when [`Cursor::step`] would move it off the
+/// edge of its [`Grid`].
+#[derive(Debug, Clone)]
+pub enum EdgePolicy {
+    /// Stepping off the border stops the cursor, returning `None`.
+    Stop,
+    /// Stepping off the border wraps around to the opposite side
+    /// (toroidal): `x` wraps modulo `width`, `y` modulo `height`.
+    Wrap,
+    /// Stepping off the border of a face folds onto the adjacent face of
+    /// the unfolded cube net described by the [`CubeNet`].
+    CubeFold(CubeNet),
+}
+
+/// A position and facing that walks a [`Grid`], per its [`EdgePolicy`].
+pub struct Cursor<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Coordinate,
+    facing: Facing,
+    policy: EdgePolicy,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Creates a new [`Cursor`] at `pos`, facing `facing`, with the given
+    /// border `policy`.
+    pub fn new(grid: &'a Grid<T>, pos: Coordinate, facing: Facing, policy: EdgePolicy) -> Self {
+        Self {
+            grid,
+            pos,
+            facing,
+            policy,
+        }
+    }
+
+    /// Returns the cursor's current position.
+    pub fn pos(&self) -> Coordinate {
+        self.pos
+    }
+
+    /// Returns the cursor's current facing.
+    pub fn facing(&self) -> Facing {
+        self.facing
+    }
+
+    /// Turns the cursor 90° clockwise in place.
+    pub fn turn_right(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+
+    /// Turns the cursor 90° counter-clockwise in place.
+    pub fn turn_left(&mut self) {
+        self.facing = self.facing.turn_left();
+    }
+
+    /// Advances the cursor one cell according to its [`EdgePolicy`],
+    /// updating its position and facing, and returns the new `(pos,
+    /// facing)`. Returns `None` (without moving) if [`EdgePolicy::Stop`]
+    /// would step off the grid.
+    pub fn step(&mut self) -> Option<(Coordinate, Facing)> {
+        let (pos, facing) = match &self.policy {
+            EdgePolicy::Stop => {
+                let (dx, dy) = self.facing.offset();
+                let x = self.pos.x as isize + dx;
+                let y = self.pos.y as isize + dy;
+                if x < 0 || y < 0 || x as usize >= self.grid.width() || y as usize >= self.grid.height()
+                {
+                    return None;
+                }
+                (Coordinate::new(x as usize, y as usize), self.facing)
+            }
+            EdgePolicy::Wrap => {
+                let (dx, dy) = self.facing.offset();
+                let w = self.grid.width() as isize;
+                let h = self.grid.height() as isize;
+                let x = (self.pos.x as isize + dx).rem_euclid(w);
+                let y = (self.pos.y as isize + dy).rem_euclid(h);
+                (Coordinate::new(x as usize, y as usize), self.facing)
+            }
+            EdgePolicy::CubeFold(net) => net.step(self.pos, self.facing),
+        };
+
+        self.pos = pos;
+        self.facing = facing;
+        Some((pos, facing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn facing_turns_and_opposite() {
+        assert_eq!(Facing::Up.turn_right(), Facing::Right);
+        assert_eq!(Facing::Up.turn_left(), Facing::Left);
+        assert_eq!(Facing::Up.opposite(), Facing::Down);
+        assert_eq!(Facing::Right.turn_right().turn_right(), Facing::Left);
+    }
+
+    #[test]
+    fn cursor_stop_returns_none_at_border() {
+        let grid = Grid::<u8>::new_default(3, 3);
+        let mut cursor = Cursor::new(&grid, Coordinate::new(0, 0), Facing::Left, EdgePolicy::Stop);
+        assert_eq!(cursor.step(), None);
+        assert_eq!(cursor.pos(), Coordinate::new(0, 0));
+    }
+
+    #[test]
+    fn cursor_wrap_is_toroidal() {
+        let grid = Grid::<u8>::new_default(3, 3);
+        let mut cursor = Cursor::new(&grid, Coordinate::new(0, 0), Facing::Up, EdgePolicy::Wrap);
+        assert_eq!(cursor.step(), Some((Coordinate::new(0, 2), Facing::Up)));
+
+        let mut cursor = Cursor::new(&grid, Coordinate::new(2, 2), Facing::Right, EdgePolicy::Wrap);
+        assert_eq!(cursor.step(), Some((Coordinate::new(0, 2), Facing::Right)));
+    }
+
+    /// The AoC 2022 day 22 sample cube net, laid out in a 4x3 grid of
+    /// 4x4-cell faces (rows without a face are blank, using `.`):
+    /// ```text
+    /// ..1.
+    /// 234.
+    /// ..56
+    /// ```
+    fn sample_net() -> Grid<char> {
+        let blank = ".".repeat(4);
+        let face = |c: char| c.to_string().repeat(4);
+
+        let mut text = String::new();
+        for _ in 0..4 {
+            text += &blank;
+            text += &blank;
+            text += &face('1');
+            text += &blank;
+            text.push('\n');
+        }
+        for _ in 0..4 {
+            text += &face('2');
+            text += &face('3');
+            text += &face('4');
+            text += &blank;
+            text.push('\n');
+        }
+        for _ in 0..4 {
+            text += &blank;
+            text += &blank;
+            text += &face('5');
+            text += &face('6');
+            text.push('\n');
+        }
+
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn cube_net_detects_six_faces() {
+        let grid = sample_net();
+        let net = CubeNet::detect(&grid, |&c| c == '.');
+        assert_eq!(net.face_size, 4);
+        assert_eq!(net.faces.len(), 6);
+        assert_eq!(net.by_normal.len(), 6);
+    }
+
+    #[test]
+    fn cube_fold_round_trips_across_every_face_edge() {
+        let grid = sample_net();
+        let net = CubeNet::detect(&grid, |&c| c == '.');
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if grid[Coordinate::new(x, y)] == '.' {
+                    continue;
+                }
+                let start = Coordinate::new(x, y);
+                for facing in Facing::ALL {
+                    let (next, new_facing) = net.step(start, facing);
+                    let (back, back_facing) = net.step(next, new_facing.opposite());
+                    assert_eq!(back, start, "at {start:?} facing {facing:?}");
+                    assert_eq!(back_facing, facing.opposite());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_cube_fold_never_lands_on_a_blank_cell() {
+        let grid = sample_net();
+        let net = CubeNet::detect(&grid, |&c| c == '.');
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if grid[Coordinate::new(x, y)] == '.' {
+                    continue;
+                }
+                for facing in Facing::ALL {
+                    let mut cursor = Cursor::new(
+                        &grid,
+                        Coordinate::new(x, y),
+                        facing,
+                        EdgePolicy::CubeFold(net.clone()),
+                    );
+                    let (pos, _) = cursor.step().unwrap();
+                    assert_ne!(grid[pos], '.', "stepped onto a blank cell from ({x}, {y})");
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 //! Types and traits used in Dijkstra's algorithm.
 
 use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt,
     hash::Hash,
     ops::Add,
 };
@@ -66,8 +67,52 @@ impl<T: Graph> Traversal<T> {
     pub fn previous(&self) -> &HashMap<T::Node, Option<T::Node>> {
         &self.previous
     }
+
+    /// Returns the shortest distance to `node`, if it was reached.
+    pub fn distance_to(&self, node: &T::Node) -> Option<&T::Distance> {
+        self.distances.get(node)
+    }
+
+    /// Reconstructs the shortest path from [`Traversal::from`] to
+    /// [`Traversal::to`], by walking [`Traversal::previous`] in reverse.
+    ///
+    /// Returns `None` if `to` was never reached (i.e., it has no entry in
+    /// `previous`).
+    pub fn path(&self) -> Option<Vec<T::Node>> {
+        // `to` must have an entry in `previous` to have been reached at all
+        if !self.previous.contains_key(&self.to) {
+            return None;
+        }
+
+        let mut current = self.to.clone();
+        let mut path = vec![current.clone()];
+        while let Some(prev) = self.previous[&current].clone() {
+            path.push(prev.clone());
+            current = prev;
+        }
+
+        path.reverse();
+        Some(path)
+    }
 }
 
+/// Error returned by [`Graph::toposort`] when the graph reachable from the
+/// given roots contains a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// A node that could not be ordered (i.e., one involved in or depending
+    /// on a cycle).
+    pub node: N,
+}
+
+impl<N: fmt::Debug> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle involving node `{:?}`", self.node)
+    }
+}
+
+impl<N: fmt::Debug> std::error::Error for CycleError<N> {}
+
 /// A type that can be pathed by [Dijkstra's algorithm].
 ///
 /// At a high level, the algorithm requires:
@@ -91,6 +136,19 @@ pub trait Graph: Sized {
     /// The type used to represent edge weights.
     type Distance: Clone + Ord + Add + Zero;
 
+    /// Returns a lazy iterator that yields settled nodes in nondecreasing
+    /// distance order from `from`.
+    ///
+    /// Unlike [`Graph::dijkstra`], this does not require a `to` node and does
+    /// not run to completion -- each call to `next` only does the work needed
+    /// to settle the next-closest node. This makes it well suited to
+    /// flood-fill/reachability queries that can stop early, such as "all nodes
+    /// within distance `D`" (`take_while`) or "first node matching some
+    /// predicate" (`find`), without paying for a full traversal.
+    fn dijkstra_iter(&self, from: Self::Node) -> DijkstraIter<'_, Self> {
+        DijkstraIter::new(self, from)
+    }
+
     /// Executes Dijkstra's algorithm and returns traversal information.
     ///
     /// This method will short circuit once finding the `to` node. Matching this
@@ -99,9 +157,27 @@ pub trait Graph: Sized {
     ///
     /// Implementation adapted from: <https://codereview.stackexchange.com/a/202879>
     fn dijkstra(&self, from: Self::Node, to: Self::Node) -> Traversal<Self> {
+        // 4-ary heaps are a reasonable default for the grid-shaped graphs this
+        // project mostly deals with -- see `Graph::dijkstra_with_arity` docs
+        self.dijkstra_with_arity::<4>(from, to)
+    }
+
+    /// Same as [`Graph::dijkstra`], but lets the caller choose the frontier's
+    /// heap arity `D` (e.g. `graph.dijkstra_with_arity::<8>(from, to)`).
+    ///
+    /// A higher arity means fewer levels in the heap (and thus fewer
+    /// comparisons/cache misses) per `push`/`pop`, at the cost of comparing
+    /// against more siblings per level. For the large, dense graphs that show
+    /// up in grid problems, `D = 4` or `D = 8` tend to win over the classic
+    /// binary heap (`D = 2`); see the `dijkstra_heap` benchmark.
+    fn dijkstra_with_arity<const D: usize>(
+        &self,
+        from: Self::Node,
+        to: Self::Node,
+    ) -> Traversal<Self> {
         let mut map = Traversal::new(from.clone(), to.clone());
         let mut visited = HashSet::new();
-        let mut queue = BinaryHeap::new();
+        let mut queue = DaryHeap::<D, _>::new();
 
         // using Zero trait allows us to generalize this for any distance type.
         // we also don't need to worry about "infinity" since the map will
@@ -147,6 +223,86 @@ pub trait Graph: Sized {
         map
     }
 
+    /// Executes A* search and returns traversal information.
+    ///
+    /// This behaves identically to [`Graph::dijkstra`], except the frontier is
+    /// ordered by `f = g + h` instead of just `g` (the distance from `from`).
+    /// The `g` score for each node is still tracked in [`Traversal::distances`]
+    /// and `previous` is updated using the same rules as [`Graph::dijkstra`] --
+    /// only the priority used to order the frontier heap changes.
+    ///
+    /// This method will short circuit once finding the `to` node, using the
+    /// same [`Graph::nodes_eq`] and [`Graph::is_done`] checks as
+    /// [`Graph::dijkstra`].
+    ///
+    /// # Heuristic requirements
+    ///
+    /// [`Graph::heuristic`] must never overestimate the true remaining
+    /// distance to `to` (i.e., it must be *admissible*). If this invariant does
+    /// not hold, the shortest path is no longer guaranteed -- the same way
+    /// [`Graph::edge`] must never return a negative distance.
+    fn astar(&self, from: Self::Node, to: Self::Node) -> Traversal<Self> {
+        self.astar_with_arity::<4>(from, to)
+    }
+
+    /// Same as [`Graph::astar`], but lets the caller choose the frontier's
+    /// heap arity `D`, as with [`Graph::dijkstra_with_arity`].
+    fn astar_with_arity<const D: usize>(&self, from: Self::Node, to: Self::Node) -> Traversal<Self> {
+        let mut map = Traversal::new(from.clone(), to.clone());
+        let mut visited = HashSet::new();
+        let mut queue = DaryHeap::<D, _>::new();
+
+        map.distances.insert(from.clone(), Self::Distance::zero());
+        map.previous.insert(from.clone(), None);
+
+        let h = self.heuristic(&from, &to);
+        queue.push(Visit(from, h));
+        while let Some(Visit(node, _)) = queue.pop() {
+            if Self::nodes_eq(&node, &to) && self.is_done(&node, &to) {
+                map.to = node;
+                return map;
+            }
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+
+            // the priority on the heap is f = g + h, but the real distance is
+            // whatever g_score we have settled on in `map.distances`
+            let g = map.distances[&node].clone();
+            for n in self.adjacent(&node) {
+                let d = g.clone() + self.edge(&node, &n);
+
+                if map.distances.get(&n).map_or(true, |dist| &d < dist) {
+                    map.distances.remove(&n);
+                    map.previous.remove(&n);
+                    map.distances.insert(n.clone(), d.clone());
+                    map.previous.insert(n.clone(), Some(node.clone()));
+                }
+
+                let f = d + self.heuristic(&n, &to);
+                queue.push(Visit(n, f));
+            }
+        }
+
+        map
+    }
+
+    /// Returns the heuristic (estimated remaining distance) from `node` to
+    /// `to`, used to guide [`Graph::astar`].
+    ///
+    /// # Warning
+    ///
+    /// This heuristic must be *admissible* -- it must never overestimate the
+    /// true remaining distance between `node` and `to`. Otherwise, the
+    /// shortest path found by [`Graph::astar`] is not guaranteed to be
+    /// correct.
+    ///
+    /// The default implementation always returns [`Zero::zero`], which makes
+    /// [`Graph::astar`] degrade gracefully into plain [`Graph::dijkstra`].
+    fn heuristic(&self, _node: &Self::Node, _to: &Self::Node) -> Self::Distance {
+        Self::Distance::zero()
+    }
+
     /// Returns the minimum distance between nodes `from` and `to`, starting at
     /// `from`.
     ///
@@ -222,6 +378,419 @@ pub trait Graph: Sized {
     fn is_done(&self, _current: &Self::Node, _to: &Self::Node) -> bool {
         true
     }
+
+    /// Returns the strongly connected components reachable from `roots`,
+    /// using [Tarjan's algorithm].
+    ///
+    /// Each inner [`Vec`] is one strongly connected component; a DAG (no
+    /// cycles) yields only singleton components. Successors are taken from
+    /// [`Graph::adjacent`], so no new implementor method is required.
+    ///
+    /// This is implemented iteratively (an explicit DFS stack rather than
+    /// recursion) so it doesn't overflow the call stack on deep graphs.
+    ///
+    /// [Tarjan's algorithm]: https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm
+    fn tarjan_scc(&self, roots: impl IntoIterator<Item = Self::Node>) -> Vec<Vec<Self::Node>> {
+        struct Frame<N> {
+            node: N,
+            successors: Vec<N>,
+            next: usize,
+        }
+
+        let mut index = HashMap::new();
+        let mut lowlink = HashMap::new();
+        let mut on_stack = HashSet::new();
+        let mut component_stack = Vec::new();
+        let mut result = Vec::new();
+        let mut next_index = 0usize;
+
+        for root in roots {
+            if index.contains_key(&root) {
+                continue;
+            }
+
+            let mut work = vec![Frame {
+                successors: self.adjacent(&root),
+                node: root.clone(),
+                next: 0,
+            }];
+            index.insert(root.clone(), next_index);
+            lowlink.insert(root.clone(), next_index);
+            next_index += 1;
+            component_stack.push(root.clone());
+            on_stack.insert(root);
+
+            while let Some(frame) = work.last_mut() {
+                if frame.next < frame.successors.len() {
+                    let child = frame.successors[frame.next].clone();
+                    frame.next += 1;
+
+                    if !index.contains_key(&child) {
+                        index.insert(child.clone(), next_index);
+                        lowlink.insert(child.clone(), next_index);
+                        next_index += 1;
+                        component_stack.push(child.clone());
+                        on_stack.insert(child.clone());
+
+                        work.push(Frame {
+                            successors: self.adjacent(&child),
+                            node: child,
+                            next: 0,
+                        });
+                    } else if on_stack.contains(&child) {
+                        let child_index = index[&child];
+                        let entry = lowlink.get_mut(&frame.node).unwrap();
+                        *entry = (*entry).min(child_index);
+                    }
+                } else {
+                    let frame = work.pop().unwrap();
+                    let node = frame.node;
+                    let node_low = lowlink[&node];
+
+                    if let Some(parent) = work.last() {
+                        let entry = lowlink.get_mut(&parent.node).unwrap();
+                        *entry = (*entry).min(node_low);
+                    }
+
+                    if node_low == index[&node] {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = component_stack.pop().unwrap();
+                            on_stack.remove(&w);
+                            let is_root = w == node;
+                            component.push(w);
+                            if is_root {
+                                break;
+                            }
+                        }
+                        result.push(component);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns a topological ordering of the nodes reachable from `roots`,
+    /// using [Kahn's algorithm].
+    ///
+    /// Successors are taken from [`Graph::adjacent`]. Returns
+    /// [`CycleError`] containing a node that could not be ordered (i.e., one
+    /// involved in or depending on a cycle) if the reachable subgraph is not
+    /// a DAG.
+    ///
+    /// [Kahn's algorithm]: https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm
+    fn toposort(
+        &self,
+        roots: impl IntoIterator<Item = Self::Node>,
+    ) -> Result<Vec<Self::Node>, CycleError<Self::Node>> {
+        let mut in_degree = HashMap::new();
+        let mut successors = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut stack = roots.into_iter().collect::<Vec<_>>();
+
+        // discover the reachable subgraph and its in-degrees
+        while let Some(node) = stack.pop() {
+            if !seen.insert(node.clone()) {
+                continue;
+            }
+
+            in_degree.entry(node.clone()).or_insert(0usize);
+            let next = self.adjacent(&node);
+            for n in &next {
+                *in_degree.entry(n.clone()).or_insert(0) += 1;
+                if !seen.contains(n) {
+                    stack.push(n.clone());
+                }
+            }
+            successors.insert(node, next);
+        }
+
+        let mut queue = in_degree
+            .iter()
+            .filter(|&(_, &d)| d == 0)
+            .map(|(n, _)| n.clone())
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(in_degree.len());
+        let mut ordered = HashSet::new();
+
+        while let Some(node) = queue.pop_front() {
+            ordered.insert(node.clone());
+            for n in &successors[&node] {
+                let d = in_degree.get_mut(n).unwrap();
+                *d -= 1;
+                if *d == 0 {
+                    queue.push_back(n.clone());
+                }
+            }
+            order.push(node);
+        }
+
+        if order.len() != in_degree.len() {
+            let node = in_degree
+                .keys()
+                .find(|n| !ordered.contains(*n))
+                .cloned()
+                .expect("unordered node must exist when order is incomplete");
+            return Err(CycleError { node });
+        }
+
+        Ok(order)
+    }
+}
+
+/// Lazy iterator form of Dijkstra's algorithm, returned by [`Graph::dijkstra_iter`].
+///
+/// Yields `(node, distance)` pairs in nondecreasing distance order from the
+/// starting node, settling exactly one node per call to `next`.
+pub struct DijkstraIter<'a, T: Graph> {
+    graph: &'a T,
+    visited: HashSet<T::Node>,
+    queue: BinaryHeap<Visit<T::Node, T::Distance>>,
+}
+
+impl<'a, T: Graph> DijkstraIter<'a, T> {
+    fn new(graph: &'a T, from: T::Node) -> Self {
+        let mut queue = BinaryHeap::new();
+        queue.push(Visit(from, T::Distance::zero()));
+
+        Self {
+            graph,
+            visited: HashSet::new(),
+            queue,
+        }
+    }
+}
+
+impl<'a, T: Graph> Iterator for DijkstraIter<'a, T> {
+    type Item = (T::Node, T::Distance);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Visit(node, dist)) = self.queue.pop() {
+            if !self.visited.insert(node.clone()) {
+                continue;
+            }
+
+            for n in self.graph.adjacent(&node) {
+                if self.visited.contains(&n) {
+                    continue;
+                }
+                let d = dist.clone() + self.graph.edge(&node, &n);
+                self.queue.push(Visit(n, d));
+            }
+
+            return Some((node, dist));
+        }
+
+        None
+    }
+}
+
+/// An opaque, `Copy` identifier for a [`Graph::Node`] interned into a
+/// [`GraphArena`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(u32);
+
+/// Interns [`Graph::Node`] values into a dense arena, returning a small
+/// `Copy` [`NodeId`] in their place.
+///
+/// [`Graph::dijkstra`] clones `Self::Node` aggressively -- into `visited`,
+/// both [`Traversal`] maps, and every [`Visit`] pushed onto the heap -- which
+/// is wasteful when nodes are large (strings, coordinate structs, board
+/// states). [`GraphArena::dijkstra`] runs the same algorithm over [`NodeId`]s
+/// instead, so `visited`, the distance/previous maps, and the heap hold
+/// [`NodeId`]s rather than node clones; real `T::Node` keyed maps are only
+/// built for the returned [`Traversal`]. A node is cloned into the arena only
+/// the first time it is interned, but each relaxation still hashes the
+/// neighbor node to look up its id, and each popped node is cloned once out
+/// of the arena to pass to [`Graph::adjacent`].
+///
+/// # Limitations
+///
+/// Interning relies on [`Graph::Node`]'s [`Eq`]/[`Hash`] implementation to
+/// collapse duplicate nodes to the same [`NodeId`]. If a [`Graph`]
+/// implementation overrides [`Graph::nodes_eq`] with looser semantics than
+/// `==`, that custom equality is only consulted when checking for the `to`
+/// node -- two nodes that are `nodes_eq` but not `==` will still be interned
+/// as distinct ids.
+pub struct GraphArena<'a, T: Graph> {
+    graph: &'a T,
+    nodes: Vec<T::Node>,
+    ids: HashMap<T::Node, NodeId>,
+}
+
+impl<'a, T: Graph> GraphArena<'a, T> {
+    /// Creates a new, empty arena over the given `graph`.
+    pub fn new(graph: &'a T) -> Self {
+        Self {
+            graph,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    /// Interns `node`, returning its [`NodeId`].
+    ///
+    /// If an `==` node was already interned, returns the existing id rather
+    /// than creating a duplicate entry.
+    pub fn intern(&mut self, node: T::Node) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        let id = NodeId(self.nodes.len() as u32);
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+
+        id
+    }
+
+    /// Returns the node that `id` was interned from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` was not returned by this arena's [`GraphArena::intern`].
+    pub fn resolve(&self, id: NodeId) -> &T::Node {
+        &self.nodes[id.0 as usize]
+    }
+
+    /// Executes Dijkstra's algorithm over interned [`NodeId`]s, only
+    /// materializing `T::Node` values when building the returned
+    /// [`Traversal`].
+    ///
+    /// Behaves the same as [`Graph::dijkstra`] otherwise, including short
+    /// circuiting once `to` is found (per [`Graph::nodes_eq`]/[`Graph::is_done`]).
+    pub fn dijkstra(&mut self, from: T::Node, to: T::Node) -> Traversal<T> {
+        let from_id = self.intern(from.clone());
+
+        let mut map = Traversal::new(from.clone(), to.clone());
+        let mut visited = HashSet::new();
+        let mut distances = HashMap::new();
+        let mut previous = HashMap::new();
+        let mut queue = BinaryHeap::new();
+
+        distances.insert(from_id, T::Distance::zero());
+        previous.insert(from_id, None);
+        queue.push(Visit(from_id, T::Distance::zero()));
+
+        while let Some(Visit(id, dist)) = queue.pop() {
+            let node = self.resolve(id).clone();
+            if T::nodes_eq(&node, &to) && self.graph.is_done(&node, &to) {
+                map.to = node;
+                return materialize(&*self, map, &distances, &previous);
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+
+            for n in self.graph.adjacent(&node) {
+                let d = dist.clone() + self.graph.edge(&node, &n);
+                let nid = self.intern(n);
+
+                if distances.get(&nid).map_or(true, |x| &d < x) {
+                    distances.remove(&nid);
+                    previous.remove(&nid);
+                    distances.insert(nid, d.clone());
+                    previous.insert(nid, Some(id));
+                }
+                queue.push(Visit(nid, d));
+            }
+        }
+
+        materialize(&*self, map, &distances, &previous)
+    }
+}
+
+/// Resolves [`NodeId`]-keyed distance/previous maps back into the `T::Node`-keyed
+/// maps used by [`Traversal`].
+fn materialize<T: Graph>(
+    arena: &GraphArena<'_, T>,
+    mut map: Traversal<T>,
+    distances: &HashMap<NodeId, T::Distance>,
+    previous: &HashMap<NodeId, Option<NodeId>>,
+) -> Traversal<T> {
+    for (&id, dist) in distances {
+        map.distances.insert(arena.resolve(id).clone(), dist.clone());
+    }
+    for (&id, prev) in previous {
+        let prev = prev.map(|p| arena.resolve(p).clone());
+        map.previous.insert(arena.resolve(id).clone(), prev);
+    }
+
+    map
+}
+
+/// A minimal d-ary max-heap, used as a pluggable [`BinaryHeap`] replacement
+/// for the frontier in [`Graph::dijkstra_with_arity`]/[`Graph::astar_with_arity`].
+///
+/// This only implements the slice of API this module actually needs
+/// (`push`/`pop`), arranging elements in a `D`-ary tree instead of a binary
+/// one. `D` must be at least `2`.
+struct DaryHeap<const D: usize, T> {
+    data: Vec<T>,
+}
+
+impl<const D: usize, T: Ord> DaryHeap<D, T> {
+    fn new() -> Self {
+        debug_assert!(D >= 2, "heap arity must be at least 2");
+        Self { data: Vec::new() }
+    }
+
+    fn push(&mut self, value: T) {
+        self.data.push(value);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let value = self.data.pop();
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+
+        value
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / D;
+            if self.data[idx] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        let len = self.data.len();
+        loop {
+            let first_child = idx * D + 1;
+            if first_child >= len {
+                break;
+            }
+
+            let last_child = (first_child + D).min(len);
+            let mut largest = first_child;
+            for child in (first_child + 1)..last_child {
+                if self.data[child] > self.data[largest] {
+                    largest = child;
+                }
+            }
+
+            if self.data[largest] <= self.data[idx] {
+                break;
+            }
+            self.data.swap(idx, largest);
+            idx = largest;
+        }
+    }
 }
 
 /// Wrapper type for node-distance pairs that implements min-heap [`Ord`] logic
@@ -251,6 +820,8 @@ impl<T, D: PartialOrd> Eq for Visit<T, D> {}
 
 #[cfg(test)]
 mod tests {
+    use crate::set;
+
     use super::*;
 
     struct TestGraph;
@@ -303,4 +874,218 @@ mod tests {
         let g = TestGraph;
         assert_eq!(g.min_distance('A', 'D'), 4);
     }
+
+    #[test]
+    fn traversal_path() {
+        let g = TestGraph;
+        let map = g.dijkstra('A', 'D');
+        assert_eq!(map.path(), Some(vec!['A', 'C', 'D']));
+    }
+
+    #[test]
+    fn traversal_distance_to() {
+        let g = TestGraph;
+        let map = g.dijkstra('A', 'D');
+        assert_eq!(map.distance_to(&'C'), Some(&2));
+        assert_eq!(map.distance_to(&'D'), Some(&4));
+    }
+
+    #[test]
+    fn traversal_path_unreachable() {
+        struct Disconnected;
+
+        impl Graph for Disconnected {
+            type Node = char;
+            type Distance = usize;
+
+            fn adjacent(&self, _node: &Self::Node) -> Vec<Self::Node> {
+                vec![]
+            }
+
+            fn edge(&self, _from: &Self::Node, _to: &Self::Node) -> Self::Distance {
+                0
+            }
+        }
+
+        let g = Disconnected;
+        let map = g.dijkstra('A', 'Z');
+        assert_eq!(map.path(), None);
+        assert_eq!(map.distance_to(&'Z'), None);
+    }
+
+    #[test]
+    fn dijkstra_iter_yields_nondecreasing_distances() {
+        let g = TestGraph;
+        let visited = g.dijkstra_iter('A').collect::<Vec<_>>();
+
+        assert_eq!(visited.len(), 4);
+        assert_eq!(visited[0], ('A', 0));
+        for pair in visited.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn dijkstra_iter_take_while_distance() {
+        let g = TestGraph;
+        let within_4 = g
+            .dijkstra_iter('A')
+            .take_while(|&(_, d)| d <= 4)
+            .map(|(n, _)| n)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(within_4, set!['A', 'C', 'D']);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_with_zero_heuristic() {
+        let g = TestGraph;
+        let map = g.astar('A', 'D');
+        assert_eq!(map.distances[&'A'], 0);
+        assert_eq!(map.distances[&'B'], 5);
+        assert_eq!(map.distances[&'C'], 2);
+        assert_eq!(map.distances[&'D'], 4);
+    }
+
+    #[test]
+    fn dijkstra_with_arity_matches_default() {
+        let g = TestGraph;
+        for map in [
+            g.dijkstra_with_arity::<2>('A', 'D'),
+            g.dijkstra_with_arity::<4>('A', 'D'),
+            g.dijkstra_with_arity::<8>('A', 'D'),
+        ] {
+            assert_eq!(map.distances[&'D'], 4);
+        }
+    }
+
+    fn dary_heap_sorts_descending<const D: usize>() {
+        let mut heap = DaryHeap::<D, _>::new();
+        for v in [5, 1, 9, 3, 7, 2, 8, 4, 6, 0] {
+            heap.push(v);
+        }
+
+        let mut popped = vec![];
+        while let Some(v) = heap.pop() {
+            popped.push(v);
+        }
+        assert_eq!(popped, vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn graph_arena_intern_resolve() {
+        let g = TestGraph;
+        let mut arena = GraphArena::new(&g);
+
+        let a = arena.intern('A');
+        let b = arena.intern('B');
+        let a_again = arena.intern('A');
+
+        assert_eq!(a, a_again);
+        assert_ne!(a, b);
+        assert_eq!(*arena.resolve(a), 'A');
+        assert_eq!(*arena.resolve(b), 'B');
+    }
+
+    #[test]
+    fn graph_arena_dijkstra_matches_plain_dijkstra() {
+        let g = TestGraph;
+        let mut arena = GraphArena::new(&g);
+        let arena_map = arena.dijkstra('A', 'D');
+        let plain_map = g.dijkstra('A', 'D');
+
+        assert_eq!(arena_map.distances(), plain_map.distances());
+        assert_eq!(arena_map.path(), plain_map.path());
+    }
+
+    /// A directed graph with one cycle (A -> B -> C -> A) and a DAG tail
+    /// (C -> D -> E).
+    struct DirectedGraph;
+
+    impl Graph for DirectedGraph {
+        type Node = char;
+        type Distance = usize;
+
+        fn adjacent(&self, node: &Self::Node) -> Vec<Self::Node> {
+            match *node {
+                'A' => vec!['B'],
+                'B' => vec!['C'],
+                'C' => vec!['A', 'D'],
+                'D' => vec!['E'],
+                'E' => vec![],
+                _ => panic!("unknown node `{node}`"),
+            }
+        }
+
+        fn edge(&self, _from: &Self::Node, _to: &Self::Node) -> Self::Distance {
+            1
+        }
+    }
+
+    /// Same shape as [`DirectedGraph`], but without the `C -> A` back-edge
+    /// (making it a pure DAG).
+    struct AcyclicGraph;
+
+    impl Graph for AcyclicGraph {
+        type Node = char;
+        type Distance = usize;
+
+        fn adjacent(&self, node: &Self::Node) -> Vec<Self::Node> {
+            match *node {
+                'A' => vec!['B'],
+                'B' => vec!['C'],
+                'C' => vec!['D'],
+                'D' => vec!['E'],
+                'E' => vec![],
+                _ => panic!("unknown node `{node}`"),
+            }
+        }
+
+        fn edge(&self, _from: &Self::Node, _to: &Self::Node) -> Self::Distance {
+            1
+        }
+    }
+
+    #[test]
+    fn tarjan_scc_finds_cycle_and_singletons() {
+        let g = DirectedGraph;
+        let mut sccs = g
+            .tarjan_scc(['A'])
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect::<Vec<_>>();
+        sccs.sort();
+
+        assert_eq!(sccs, vec![vec!['A', 'B', 'C'], vec!['D'], vec!['E']]);
+    }
+
+    #[test]
+    fn toposort_dag_orders_dependencies_first() {
+        let g = AcyclicGraph;
+        let order = g.toposort(['A']).unwrap();
+
+        let pos = |n: char| order.iter().position(|&x| x == n).unwrap();
+        assert!(pos('A') < pos('B'));
+        assert!(pos('B') < pos('C'));
+        assert!(pos('C') < pos('D'));
+        assert!(pos('D') < pos('E'));
+    }
+
+    #[test]
+    fn toposort_reports_cycle() {
+        let g = DirectedGraph;
+        let err = g.toposort(['A']).unwrap_err();
+        assert!(['A', 'B', 'C'].contains(&err.node));
+    }
+
+    #[test]
+    fn dary_heap_pops_in_descending_order() {
+        dary_heap_sorts_descending::<2>();
+        dary_heap_sorts_descending::<3>();
+        dary_heap_sorts_descending::<4>();
+        dary_heap_sorts_descending::<8>();
+    }
 }
@@ -27,6 +27,29 @@ where
     Ok(items)
 }
 
+/// Splits `s` on blank lines, trims each block, and applies `f` to every
+/// block, collecting the results in a [`Vec`].
+///
+/// Generalizes [`map_file_lines`]'s "one item per line" shape to the common
+/// "groups of lines" shape: reflection maps, calorie-style totals, and
+/// passport-style records are all blocks of lines separated by a blank line.
+pub fn parse_records<F, T>(s: &str, f: F) -> Result<Vec<T>>
+where
+    F: Fn(&str) -> Result<T>,
+{
+    s.split("\n\n").map(|block| f(block.trim())).collect()
+}
+
+/// Applies [`parse_records`] to the contents of a file.
+pub fn map_file_records<P, F, T>(path: P, f: F) -> Result<Vec<T>>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Result<T>,
+{
+    let contents = std::fs::read_to_string(path)?;
+    parse_records(&contents, f)
+}
+
 /// Parse a value from file contents.
 ///
 /// This trait is most commonly used with types that implement [`FromStr`].
@@ -56,3 +79,41 @@ where
         Self::from_str(&s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::day13::Map;
+
+    #[test]
+    fn parse_records_splits_on_blank_lines() {
+        let records = parse_records("a\nb\n\nc\nd\n\ne", |s| Ok(s.to_string())).unwrap();
+        assert_eq!(records, vec!["a\nb", "c\nd", "e"]);
+    }
+
+    #[test]
+    fn parse_records_round_trips_with_map_from_str() {
+        let input = "\
+            #.##..##.\n\
+            ..#.##.#.\n\
+            ##......#\n\
+            ##......#\n\
+            ..#.##.#.\n\
+            ..##..##.\n\
+            #.#.##.#.\n\
+            \n\
+            #...##..#\n\
+            #....#..#\n\
+            ..##..###\n\
+            #####.##.\n\
+            #####.##.\n\
+            ..##..###\n\
+            #....#..#";
+
+        let maps = parse_records(input, str::parse::<Map>).unwrap();
+
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[0].find_reflection().summarize(), 5);
+        assert_eq!(maps[1].find_reflection().summarize(), 400);
+    }
+}
@@ -1,14 +1,192 @@
 use std::{
+    collections::{HashSet, VecDeque},
     fmt::{self, Write},
     ops::{Index, IndexMut},
     str::FromStr,
 };
 
 use anyhow::{bail, Result};
-use num::range_step;
 
 use crate::Coordinate;
 
+/// An axis-aligned rectangular region within a [`Grid`], used by
+/// [`Grid::view`]/[`Grid::view_mut`]/[`Grid::crop`] to address a bounded
+/// window rather than the whole grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub origin: Coordinate,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    /// Creates a new [`Rect`] anchored at `origin` with the given `width`
+    /// and `height`.
+    pub fn new(origin: Coordinate, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// Returns `true` if this rect has zero width or height.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns `true` if this rect lies entirely within a grid of the given
+    /// `grid_width` x `grid_height`.
+    fn fits_within(&self, grid_width: usize, grid_height: usize) -> bool {
+        self.origin.x.saturating_add(self.width) <= grid_width
+            && self.origin.y.saturating_add(self.height) <= grid_height
+    }
+
+    /// Translates a coordinate local to this rect into the parent grid's
+    /// coordinate space.
+    fn to_parent(self, local: Coordinate) -> Coordinate {
+        Coordinate::new(self.origin.x + local.x, self.origin.y + local.y)
+    }
+}
+
+/// A borrowed, [`Rect`]-bounded window into a [`Grid`], created by
+/// [`Grid::view`].
+///
+/// Every coordinate passed to [`GridView`]'s methods is relative to the
+/// view's own `(0, 0)`, i.e. [`Rect::origin`], not the parent grid's.
+#[derive(Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect,
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the number of columns in the view.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.rect.width
+    }
+
+    /// Returns the number of rows in the view.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.rect.height
+    }
+
+    /// Returns the element at the given view-local coordinate, if it exists.
+    pub fn get<C: Into<Coordinate>>(&self, pos: C) -> Option<&'a T> {
+        let c: Coordinate = pos.into();
+        if c.x >= self.rect.width || c.y >= self.rect.height {
+            return None;
+        }
+
+        self.grid.get(self.rect.to_parent(c))
+    }
+
+    /// Returns an iterator over the rows of the view, each translated to the
+    /// parent grid's underlying cells.
+    pub fn rows(&self) -> impl Iterator<Item = Vec<&'a T>> + '_ {
+        (0..self.rect.height).map(move |y| {
+            (0..self.rect.width)
+                .map(move |x| &self.grid[self.rect.to_parent(Coordinate::new(x, y))])
+                .collect()
+        })
+    }
+
+    /// Returns an iterator over the columns of the view, each translated to
+    /// the parent grid's underlying cells.
+    pub fn columns(&self) -> impl Iterator<Item = Vec<&'a T>> + '_ {
+        (0..self.rect.width).map(move |x| {
+            (0..self.rect.height)
+                .map(move |y| &self.grid[self.rect.to_parent(Coordinate::new(x, y))])
+                .collect()
+        })
+    }
+
+    /// Returns an iterator over every view-local coordinate and its element,
+    /// in left-to-right, top-to-bottom order.
+    pub fn enumerate_coords(&self) -> impl Iterator<Item = (Coordinate, &'a T)> + '_ {
+        (0..self.rect.height).flat_map(move |y| {
+            (0..self.rect.width).map(move |x| {
+                let local = Coordinate::new(x, y);
+                (local, &self.grid[self.rect.to_parent(local)])
+            })
+        })
+    }
+}
+
+impl<'a, T> Index<Coordinate> for GridView<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: Coordinate) -> &Self::Output {
+        debug_assert!(index.x < self.rect.width && index.y < self.rect.height);
+        &self.grid[self.rect.to_parent(index)]
+    }
+}
+
+/// A mutably borrowed, [`Rect`]-bounded window into a [`Grid`], created by
+/// [`Grid::view_mut`].
+///
+/// Every coordinate passed to [`GridViewMut`]'s methods is relative to the
+/// view's own `(0, 0)`, i.e. [`Rect::origin`], not the parent grid's.
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    rect: Rect,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Returns the number of columns in the view.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.rect.width
+    }
+
+    /// Returns the number of rows in the view.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.rect.height
+    }
+
+    /// Returns the element at the given view-local coordinate, if it exists.
+    pub fn get<C: Into<Coordinate>>(&self, pos: C) -> Option<&T> {
+        let c: Coordinate = pos.into();
+        if c.x >= self.rect.width || c.y >= self.rect.height {
+            return None;
+        }
+
+        self.grid.get(self.rect.to_parent(c))
+    }
+
+    /// Returns a mutable reference to the element at the given view-local
+    /// coordinate, if it exists.
+    pub fn get_mut<C: Into<Coordinate>>(&mut self, pos: C) -> Option<&mut T> {
+        let c: Coordinate = pos.into();
+        if c.x >= self.rect.width || c.y >= self.rect.height {
+            return None;
+        }
+
+        let parent = self.rect.to_parent(c);
+        self.grid.get_mut(parent)
+    }
+}
+
+impl<'a, T> Index<Coordinate> for GridViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: Coordinate) -> &Self::Output {
+        debug_assert!(index.x < self.rect.width && index.y < self.rect.height);
+        &self.grid[self.rect.to_parent(index)]
+    }
+}
+
+impl<'a, T> IndexMut<Coordinate> for GridViewMut<'a, T> {
+    fn index_mut(&mut self, index: Coordinate) -> &mut Self::Output {
+        debug_assert!(index.x < self.rect.width && index.y < self.rect.height);
+        let parent = self.rect.to_parent(index);
+        &mut self.grid[parent]
+    }
+}
+
 /// An iterator that yields each column of the underlying [Grid].
 pub struct ColumnIter<'a, T> {
     grid: &'a Grid<T>,
@@ -63,12 +241,118 @@ impl<'a, T> DoubleEndedIterator for ColumnIter<'a, T> {
 
 impl<'a, T> ExactSizeIterator for ColumnIter<'a, T> {}
 
+/// The physical arrangement of a [`Grid`]'s cells in `inner`, abstracting
+/// `to_index`/`coord_at` so the same public API works regardless of
+/// backing storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Layout {
+    /// Cells are stored in a single row-major array: `index = y * width + x`.
+    RowMajor,
+    /// Cells are stored in fixed `block_size x block_size` tiles (row-major
+    /// within each tile, tiles laid out row-major), so that walking a
+    /// column touches far fewer cache lines than row-major's `width`
+    /// stride.
+    Blocked { block_size: usize },
+}
+
+impl Layout {
+    /// Converts a logical `(x, y)` coordinate into its physical index into
+    /// `inner`, for a grid of the given `width`.
+    fn to_index(self, pos: Coordinate, width: usize) -> usize {
+        match self {
+            Layout::RowMajor => pos.y * width + pos.x,
+            Layout::Blocked { block_size } => {
+                let block_cols = width / block_size;
+                let (bx, by) = (pos.x / block_size, pos.y / block_size);
+                let (lx, ly) = (pos.x % block_size, pos.y % block_size);
+
+                (by * block_cols + bx) * block_size * block_size + ly * block_size + lx
+            }
+        }
+    }
+
+    /// Converts a physical index into `inner` back into its logical
+    /// `(x, y)` coordinate, for a grid of the given `width`. Inverse of
+    /// [`Layout::to_index`].
+    fn coord_at(self, idx: usize, width: usize) -> Coordinate {
+        match self {
+            Layout::RowMajor => Coordinate::new(idx % width, idx / width),
+            Layout::Blocked { block_size } => {
+                let block_cols = width / block_size;
+                let cells_per_block = block_size * block_size;
+                let (block, local) = (idx / cells_per_block, idx % cells_per_block);
+                let (bx, by) = (block % block_cols, block / block_cols);
+                let (lx, ly) = (local % block_size, local / block_size);
+
+                Coordinate::new(bx * block_size + lx, by * block_size + ly)
+            }
+        }
+    }
+}
+
+/// A single tile's worth of cells from a [`Grid::new_blocked`] grid, in
+/// row-major order relative to `origin`, as yielded by [`Grid::blocks`].
+pub struct Block<'a, T> {
+    pub origin: Coordinate,
+    pub size: usize,
+    cells: &'a [T],
+}
+
+impl<'a, T> Block<'a, T> {
+    /// Returns this block's cells, in row-major order relative to `origin`.
+    pub fn cells(&self) -> &'a [T] {
+        self.cells
+    }
+}
+
+/// An iterator that yields each [`Block`] of a [`Grid::new_blocked`] grid,
+/// one tile at a time, as returned by [`Grid::blocks`].
+pub struct BlockIter<'a, T> {
+    grid: &'a Grid<T>,
+    block_size: usize,
+    block_cols: usize,
+    idx: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for BlockIter<'a, T> {
+    type Item = Block<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+
+        let cells_per_block = self.block_size * self.block_size;
+        let start = self.idx * cells_per_block;
+        let origin = Coordinate::new(
+            (self.idx % self.block_cols) * self.block_size,
+            (self.idx / self.block_cols) * self.block_size,
+        );
+        self.idx += 1;
+
+        Some(Block {
+            origin,
+            size: self.block_size,
+            cells: &self.grid.inner[start..start + cells_per_block],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for BlockIter<'a, T> {}
+
 /// A two-dimensional data structure used to represent maps, game boards, or any
 /// other aligned cells of any data.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     inner: Vec<T>,
     width: usize,
+    layout: Layout,
 }
 
 impl<T> Grid<T> {
@@ -86,7 +370,58 @@ impl<T> Grid<T> {
         let inner = it.collect::<Vec<_>>();
         debug_assert!(inner.len() % width == 0);
 
-        Self { inner, width }
+        Self {
+            inner,
+            width,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Creates a new, block-tiled [`Grid`] from an iterator and a `width`,
+    /// storing cells in `block_size x block_size` tiles (row-major within
+    /// each tile, tiles laid out row-major) instead of a single row-major
+    /// array.
+    ///
+    /// This trades [`Grid::row`]/[`Grid::rows`] (which require a row-major
+    /// grid) for much better cache locality on column-heavy access patterns:
+    /// walking a column only has to jump between nearby tiles, rather than
+    /// striding the full `width` on every step. Use [`Grid::blocks`] to
+    /// traverse the grid one tile at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `block_size` is `0`, if the number of elements
+    /// is not divisible by `width`, or if `block_size` doesn't evenly divide
+    /// both `width` and the resulting height.
+    pub fn new_blocked<It>(it: It, width: usize, block_size: usize) -> Self
+    where
+        It: Iterator<Item = T>,
+    {
+        debug_assert!(width > 0 && block_size > 0);
+        let items = it.collect::<Vec<_>>();
+        debug_assert!(items.len() % width == 0);
+        let height = items.len() / width;
+        debug_assert!(
+            width.is_multiple_of(block_size) && height.is_multiple_of(block_size),
+            "block_size must evenly divide both width and height"
+        );
+
+        let layout = Layout::Blocked { block_size };
+        let mut slots: Vec<Option<T>> = (0..items.len()).map(|_| None).collect();
+        for (idx, item) in items.into_iter().enumerate() {
+            let pos = Coordinate::new(idx % width, idx / width);
+            slots[layout.to_index(pos, width)] = Some(item);
+        }
+        let inner = slots
+            .into_iter()
+            .map(|slot| slot.expect("every slot is filled exactly once"))
+            .collect();
+
+        Self {
+            inner,
+            width,
+            layout,
+        }
     }
 
     /// Returns the number of columns in the grid.
@@ -124,7 +459,18 @@ impl<T> Grid<T> {
             return None;
         }
 
-        self.inner.get(c.to_index(self.width))
+        self.inner.get(self.layout.to_index(c, self.width))
+    }
+
+    /// Returns a mutable reference to the element at the given coordinate, if
+    /// it exists.
+    pub fn get_mut<C: Into<Coordinate>>(&mut self, pos: C) -> Option<&mut T> {
+        let c: Coordinate = pos.into();
+        if c.x >= self.width() || c.y >= self.height() {
+            return None;
+        }
+
+        self.inner.get_mut(self.layout.to_index(c, self.width))
     }
 
     /// Checks if the provided coordinate is within the grid bounds.
@@ -143,8 +489,10 @@ impl<T> Grid<T> {
         let c1: Coordinate = a.into();
         let c2: Coordinate = b.into();
 
-        self.inner
-            .swap(c1.to_index(self.width), c2.to_index(self.width));
+        self.inner.swap(
+            self.layout.to_index(c1, self.width),
+            self.layout.to_index(c2, self.width),
+        );
     }
 
     pub fn iter(&self) -> core::slice::Iter<'_, T> {
@@ -166,15 +514,21 @@ impl<T> Grid<T> {
         self.inner
             .iter()
             .enumerate()
-            .map(|(idx, el)| (Coordinate::from_index(idx, self.width), el))
+            .map(|(idx, el)| (self.layout.coord_at(idx, self.width), el))
     }
 
     /// Returns the row at the given `index`.
     ///
     /// # Panics
     ///
-    /// Panics if the row `index` is out of bounds.
+    /// Panics if the row `index` is out of bounds, or if this grid was
+    /// created with [`Grid::new_blocked`] (use [`Grid::blocks`] instead,
+    /// since a blocked grid's rows aren't contiguous in memory).
     pub fn row(&self, index: usize) -> &[T] {
+        assert!(
+            matches!(self.layout, Layout::RowMajor),
+            "row() requires a row-major grid; use Grid::blocks() for a blocked grid"
+        );
         assert!(index < self.height());
         let start = index * self.width;
         let end = start + self.width;
@@ -183,7 +537,18 @@ impl<T> Grid<T> {
     }
 
     /// Returns an iterator over the rows of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this grid was created with [`Grid::new_blocked`] (use
+    /// [`Grid::blocks`] instead, since a blocked grid's rows aren't
+    /// contiguous in memory).
     pub fn rows(&self) -> impl DoubleEndedIterator + ExactSizeIterator<Item = &'_ [T]> + '_ {
+        assert!(
+            matches!(self.layout, Layout::RowMajor),
+            "rows() requires a row-major grid; use Grid::blocks() for a blocked grid"
+        );
+
         self.inner.chunks(self.width)
     }
 
@@ -199,13 +564,32 @@ impl<T> Grid<T> {
         assert!(index < self.width());
 
         let mut vals = Vec::with_capacity(self.height());
-        for i in range_step(index, self.len(), self.width()) {
+        for y in 0..self.height() {
+            let i = self.layout.to_index(Coordinate::new(index, y), self.width);
             vals.push(&self.inner[i]);
         }
 
         vals
     }
 
+    /// Returns an iterator over each block of a [`Grid::new_blocked`] grid,
+    /// one tile at a time, or `None` if this grid is row-major.
+    pub fn blocks(&self) -> Option<BlockIter<'_, T>> {
+        let Layout::Blocked { block_size } = self.layout else {
+            return None;
+        };
+
+        let block_cols = self.width / block_size;
+        let block_rows = self.height() / block_size;
+        Some(BlockIter {
+            grid: self,
+            block_size,
+            block_cols,
+            idx: 0,
+            end: block_cols * block_rows,
+        })
+    }
+
     /// Returns an iterator over the columns of the grid.
     ///
     /// Note that this method does require allocating a [`Vec<&T>`] with `N`
@@ -215,6 +599,183 @@ impl<T> Grid<T> {
     pub fn columns(&self) -> ColumnIter<'_, T> {
         ColumnIter::new(self)
     }
+
+    /// Returns an iterator over the (up to 4) orthogonally adjacent
+    /// in-bounds coordinates and their elements, in the same order as
+    /// [`Coordinate::neighbors4`].
+    pub fn neighbors4<C: Into<Coordinate>>(
+        &self,
+        pos: C,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        let c: Coordinate = pos.into();
+        let candidates: Vec<Coordinate> = c.neighbors4().collect();
+
+        candidates
+            .into_iter()
+            .filter_map(move |n| self.get(n).map(|v| (n, v)))
+    }
+
+    /// Returns an iterator over the (up to 8) orthogonally and diagonally
+    /// adjacent in-bounds coordinates and their elements, in the same order
+    /// as [`Coordinate::neighbors8`].
+    pub fn neighbors8<C: Into<Coordinate>>(
+        &self,
+        pos: C,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        let c: Coordinate = pos.into();
+        let candidates: Vec<Coordinate> = c.neighbors8().collect();
+
+        candidates
+            .into_iter()
+            .filter_map(move |n| self.get(n).map(|v| (n, v)))
+    }
+
+    /// Same as [`Grid::neighbors4`], but yields just the coordinates.
+    pub fn neighbor_coords4<C: Into<Coordinate>>(
+        &self,
+        pos: C,
+    ) -> impl Iterator<Item = Coordinate> + '_ {
+        let c: Coordinate = pos.into();
+        let candidates: Vec<Coordinate> = c.neighbors4().collect();
+
+        candidates.into_iter().filter(move |&n| self.contains(n))
+    }
+
+    /// Same as [`Grid::neighbors8`], but yields just the coordinates.
+    pub fn neighbor_coords8<C: Into<Coordinate>>(
+        &self,
+        pos: C,
+    ) -> impl Iterator<Item = Coordinate> + '_ {
+        let c: Coordinate = pos.into();
+        let candidates: Vec<Coordinate> = c.neighbors8().collect();
+
+        candidates.into_iter().filter(move |&n| self.contains(n))
+    }
+
+    /// Returns the set of coordinates 4-connected to `start` (inclusive)
+    /// where `pred` holds, via breadth-first search with a [`VecDeque`]
+    /// frontier. If `start` itself fails `pred` (or is out of bounds),
+    /// returns an empty set.
+    pub fn flood_fill(&self, start: Coordinate, pred: impl Fn(&T) -> bool) -> HashSet<Coordinate> {
+        self.flood_fill_with(start, pred, false)
+    }
+
+    /// Same as [`Grid::flood_fill`], but also connects diagonally adjacent
+    /// cells (Moore neighborhood).
+    pub fn flood_fill8(
+        &self,
+        start: Coordinate,
+        pred: impl Fn(&T) -> bool,
+    ) -> HashSet<Coordinate> {
+        self.flood_fill_with(start, pred, true)
+    }
+
+    fn flood_fill_with(
+        &self,
+        start: Coordinate,
+        pred: impl Fn(&T) -> bool,
+        diagonal: bool,
+    ) -> HashSet<Coordinate> {
+        let mut visited = HashSet::new();
+        let Some(first) = self.get(start) else {
+            return visited;
+        };
+        if !pred(first) {
+            return visited;
+        }
+
+        visited.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            let neighbors: Vec<_> = if diagonal {
+                self.neighbor_coords8(pos).collect()
+            } else {
+                self.neighbor_coords4(pos).collect()
+            };
+            for next in neighbors {
+                if !visited.contains(&next) && pred(&self[next]) {
+                    visited.insert(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Labels every cell matching `pred` into disjoint 4-connected regions,
+    /// by repeatedly running [`Grid::flood_fill`] from the first unlabeled
+    /// matching cell found, in `enumerate_coords` order.
+    pub fn connected_components(&self, pred: impl Fn(&T) -> bool) -> Vec<HashSet<Coordinate>> {
+        self.connected_components_with(pred, false)
+    }
+
+    /// Same as [`Grid::connected_components`], but connects diagonally
+    /// adjacent cells (Moore neighborhood).
+    pub fn connected_components8(&self, pred: impl Fn(&T) -> bool) -> Vec<HashSet<Coordinate>> {
+        self.connected_components_with(pred, true)
+    }
+
+    fn connected_components_with(
+        &self,
+        pred: impl Fn(&T) -> bool,
+        diagonal: bool,
+    ) -> Vec<HashSet<Coordinate>> {
+        let mut seen = HashSet::new();
+        let mut components = vec![];
+
+        for (pos, value) in self.enumerate_coords() {
+            if seen.contains(&pos) || !pred(value) {
+                continue;
+            }
+
+            let region = if diagonal {
+                self.flood_fill8(pos, &pred)
+            } else {
+                self.flood_fill(pos, &pred)
+            };
+            seen.extend(region.iter().copied());
+            components.push(region);
+        }
+
+        components
+    }
+
+    /// Returns a grid the same size as this one, where each cell matching
+    /// `pred` holds the index of its [`Grid::connected_components`] region,
+    /// and every other cell holds `None`.
+    pub fn component_map(&self, pred: impl Fn(&T) -> bool) -> Grid<Option<usize>> {
+        let components = self.connected_components(pred);
+        let mut ids = vec![None; self.len()];
+        for (id, region) in components.iter().enumerate() {
+            for &pos in region {
+                ids[self.layout.to_index(pos, self.width)] = Some(id);
+            }
+        }
+
+        Grid {
+            inner: ids,
+            width: self.width,
+            layout: self.layout,
+        }
+    }
+
+    /// Returns a [`GridView`] bounded by `rect`, or `None` if `rect` doesn't
+    /// fit entirely within this grid.
+    pub fn view(&self, rect: Rect) -> Option<GridView<'_, T>> {
+        rect.fits_within(self.width(), self.height())
+            .then_some(GridView { grid: self, rect })
+    }
+
+    /// Returns a mutable [`GridViewMut`] bounded by `rect`, or `None` if
+    /// `rect` doesn't fit entirely within this grid.
+    pub fn view_mut(&mut self, rect: Rect) -> Option<GridViewMut<'_, T>> {
+        if !rect.fits_within(self.width(), self.height()) {
+            return None;
+        }
+
+        Some(GridViewMut { grid: self, rect })
+    }
 }
 
 impl<T: Default> Grid<T> {
@@ -225,7 +786,41 @@ impl<T: Default> Grid<T> {
         let mut inner = Vec::with_capacity(size);
         (0..size).for_each(|_| inner.push(T::default()));
 
-        Self { inner, width }
+        Self {
+            inner,
+            width,
+            layout: Layout::RowMajor,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Materializes the cells within `rect` as a new, owned [`Grid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rect` doesn't fit within this grid's bounds, or if `rect`
+    /// is empty, since a zero-width or zero-height [`Grid`] can't be
+    /// represented.
+    pub fn crop(&self, rect: Rect) -> Grid<T> {
+        assert!(
+            rect.fits_within(self.width(), self.height()),
+            "rect must fit within the grid"
+        );
+        assert!(!rect.is_empty(), "rect must have nonzero width and height");
+
+        let mut inner = Vec::with_capacity(rect.width * rect.height);
+        for y in 0..rect.height {
+            for x in 0..rect.width {
+                inner.push(self[rect.to_parent(Coordinate::new(x, y))].clone());
+            }
+        }
+
+        Grid {
+            inner,
+            width: rect.width,
+            layout: Layout::RowMajor,
+        }
     }
 }
 
@@ -235,7 +830,8 @@ impl<T: Copy> Grid<T> {
         assert!(index < self.height());
 
         let mut vals = Vec::with_capacity(self.height());
-        for i in range_step(index, self.len(), self.width()) {
+        for y in 0..self.height() {
+            let i = self.layout.to_index(Coordinate::new(index, y), self.width);
             vals.push(self.inner[i]);
         }
 
@@ -248,14 +844,14 @@ impl<T> Index<Coordinate> for Grid<T> {
 
     fn index(&self, index: Coordinate) -> &Self::Output {
         debug_assert!(index.x < self.width() && index.y < self.height());
-        &self.inner[index.to_index(self.width)]
+        &self.inner[self.layout.to_index(index, self.width)]
     }
 }
 
 impl<T> IndexMut<Coordinate> for Grid<T> {
     fn index_mut(&mut self, index: Coordinate) -> &mut Self::Output {
         debug_assert!(index.x < self.width() && index.y < self.height());
-        &mut self.inner[index.to_index(self.width)]
+        &mut self.inner[self.layout.to_index(index, self.width)]
     }
 }
 
@@ -291,11 +887,13 @@ impl<T> IndexMut<(usize, usize)> for Grid<T> {
 
 impl<T: fmt::Display> fmt::Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (i, value) in self.inner.iter().enumerate() {
-            if i > 0 && i % self.width() == 0 {
+        for y in 0..self.height() {
+            if y > 0 {
                 f.write_char('\n')?;
             }
-            value.fmt(f)?;
+            for x in 0..self.width() {
+                self[Coordinate::new(x, y)].fmt(f)?;
+            }
         }
 
         Ok(())
@@ -305,11 +903,11 @@ impl<T: fmt::Display> fmt::Display for Grid<T> {
 impl<T: fmt::Display> fmt::Debug for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Grid {")?;
-        for (i, value) in self.inner.iter().enumerate() {
-            if i % self.width() == 0 {
-                f.write_str("\n    ")?;
+        for y in 0..self.height() {
+            f.write_str("\n    ")?;
+            for x in 0..self.width() {
+                write!(f, "{}", self[Coordinate::new(x, y)])?;
             }
-            write!(f, "{value}")?;
         }
         f.write_str("\n}")
     }
@@ -347,6 +945,7 @@ impl<T: From<char>> FromStr for Grid<T> {
         Ok(Self {
             inner,
             width: width.unwrap(),
+            layout: Layout::RowMajor,
         })
     }
 }
@@ -429,6 +1028,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn grid_view_translates_local_coords() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        let view = g.view(Rect::new(Coordinate::new(1, 1), 2, 2)).unwrap();
+
+        assert_eq!(view.width(), 2);
+        assert_eq!(view.height(), 2);
+        assert_eq!(view.get((0, 0)), Some(&'f'));
+        assert_eq!(view.get((1, 0)), Some(&'g'));
+        assert_eq!(view.get((0, 1)), Some(&'j'));
+        assert_eq!(view.get((1, 1)), Some(&'k'));
+        assert_eq!(view.get((2, 0)), None);
+
+        let coords: Vec<_> = view.enumerate_coords().map(|(c, &v)| (c, v)).collect();
+        assert_eq!(
+            coords,
+            vec![
+                (Coordinate::new(0, 0), 'f'),
+                (Coordinate::new(1, 0), 'g'),
+                (Coordinate::new(0, 1), 'j'),
+                (Coordinate::new(1, 1), 'k'),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_view_rejects_out_of_bounds_rect() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        assert!(g.view(Rect::new(Coordinate::new(3, 0), 2, 1)).is_none());
+    }
+
+    #[test]
+    fn grid_view_supports_zero_area_rect() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        let view = g.view(Rect::new(Coordinate::new(1, 1), 0, 0)).unwrap();
+
+        assert_eq!(view.rows().count(), 0);
+        assert_eq!(view.get((0, 0)), None);
+    }
+
+    #[test]
+    fn grid_view_mut_writes_through_to_parent() {
+        let mut g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        {
+            let mut view = g.view_mut(Rect::new(Coordinate::new(1, 1), 2, 2)).unwrap();
+            *view.get_mut((0, 0)).unwrap() = 'X';
+        }
+
+        assert_eq!(g[Coordinate::new(1, 1)], 'X');
+    }
+
+    #[test]
+    fn grid_crop_materializes_owned_subgrid() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        let cropped = g.crop(Rect::new(Coordinate::new(1, 1), 2, 2));
+
+        assert_eq!(cropped.width(), 2);
+        assert_eq!(cropped.height(), 2);
+        assert_eq!(cropped.row(0), ['f', 'g']);
+        assert_eq!(cropped.row(1), ['j', 'k']);
+    }
+
     #[test]
     fn grid_columns() {
         let cols = vec![
@@ -445,4 +1106,168 @@ mod tests {
             assert_eq!(col, expected);
         }
     }
+
+    #[test]
+    fn grid_blocked_indexing_matches_row_major() {
+        let row_major = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        let blocked = Grid::new_blocked(ALPHABET_4X6.into_iter().flatten(), 4, 2);
+
+        assert_eq!(blocked.width(), 4);
+        assert_eq!(blocked.height(), 6);
+        for y in 0..row_major.height() {
+            for x in 0..row_major.width() {
+                let c = Coordinate::new(x, y);
+                assert_eq!(blocked[c], row_major[c], "at ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn grid_blocked_column_matches_row_major() {
+        let row_major = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        let blocked = Grid::new_blocked(ALPHABET_4X6.into_iter().flatten(), 4, 2);
+
+        for i in 0..row_major.width() {
+            assert_eq!(blocked.column(i), row_major.column(i));
+            assert_eq!(blocked.column_copied(i), row_major.column_copied(i));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_blocked_rows_panics() {
+        let blocked = Grid::new_blocked(ALPHABET_4X6.into_iter().flatten(), 4, 2);
+        blocked.rows().for_each(drop);
+    }
+
+    #[test]
+    fn grid_row_major_has_no_blocks() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+        assert!(g.blocks().is_none());
+    }
+
+    #[test]
+    fn grid_blocks_visits_each_tile_contiguously() {
+        let blocked = Grid::new_blocked(ALPHABET_4X6.into_iter().flatten(), 4, 2);
+        let blocks: Vec<_> = blocked.blocks().unwrap().collect();
+
+        assert_eq!(blocks.len(), 6);
+        assert_eq!(blocks[0].origin, Coordinate::new(0, 0));
+        assert_eq!(blocks[0].cells(), &['a', 'b', 'e', 'f']);
+        assert_eq!(blocks[1].origin, Coordinate::new(2, 0));
+        assert_eq!(blocks[1].cells(), &['c', 'd', 'g', 'h']);
+    }
+
+    #[test]
+    fn grid_neighbors4_excludes_out_of_bounds() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+
+        let corner: Vec<_> = g.neighbors4((0, 0)).map(|(c, &v)| (c, v)).collect();
+        assert_eq!(
+            corner,
+            vec![(Coordinate::new(1, 0), 'b'), (Coordinate::new(0, 1), 'e')]
+        );
+
+        let interior: Vec<_> = g.neighbor_coords4((1, 1)).collect();
+        assert_eq!(
+            interior,
+            vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(2, 1),
+                Coordinate::new(1, 2),
+                Coordinate::new(0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_neighbors8_includes_diagonals() {
+        let g = Grid::new(ALPHABET_4X6.into_iter().flatten(), 4);
+
+        let interior: Vec<_> = g.neighbors8((1, 1)).map(|(c, &v)| (c, v)).collect();
+        assert_eq!(interior.len(), 8);
+        assert!(interior.contains(&(Coordinate::new(0, 0), 'a')));
+        assert!(interior.contains(&(Coordinate::new(2, 2), 'k')));
+
+        let corner: Vec<_> = g.neighbor_coords8((0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 1),
+            ]
+        );
+    }
+
+    static REGIONS_5X3: &str = "\
+        AABAA\n\
+        ABBBA\n\
+        AABAA\n";
+
+    #[test]
+    fn grid_flood_fill_gathers_matching_region() {
+        let g: Grid<char> = REGIONS_5X3.parse().unwrap();
+
+        let region = g.flood_fill(Coordinate::new(1, 1), |&c| c == 'B');
+        assert_eq!(
+            region,
+            [
+                Coordinate::new(1, 1),
+                Coordinate::new(2, 1),
+                Coordinate::new(3, 1),
+                Coordinate::new(2, 0),
+                Coordinate::new(2, 2),
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+
+    #[test]
+    fn grid_flood_fill_start_failing_pred_is_empty() {
+        let g: Grid<char> = REGIONS_5X3.parse().unwrap();
+        assert!(g.flood_fill(Coordinate::new(0, 0), |&c| c == 'B').is_empty());
+    }
+
+    #[test]
+    fn grid_connected_components_labels_disjoint_regions() {
+        let g: Grid<char> = REGIONS_5X3.parse().unwrap();
+        let components = g.connected_components(|&c| c == 'A');
+
+        // the two corner triangles of 'A's are 4-connected-disjoint from
+        // each other, despite sharing the predicate.
+        assert_eq!(components.len(), 2);
+        assert_eq!(
+            components.iter().map(HashSet::len).sum::<usize>(),
+            g.enumerate_coords().filter(|&(_, &c)| c == 'A').count()
+        );
+    }
+
+    #[test]
+    fn grid_connected_components8_merges_diagonal_regions() {
+        static CORNERS_3X3: &str = "A.A\n.A.\nA.A\n";
+        let g: Grid<char> = CORNERS_3X3.parse().unwrap();
+
+        // orthogonally, all five 'A's are isolated from each other.
+        assert_eq!(g.connected_components(|&c| c == 'A').len(), 5);
+
+        // diagonally, the center 'A' touches every corner, merging them
+        // into a single region.
+        assert_eq!(g.connected_components8(|&c| c == 'A').len(), 1);
+    }
+
+    #[test]
+    fn grid_component_map_matches_connected_components() {
+        let g: Grid<char> = REGIONS_5X3.parse().unwrap();
+        let components = g.connected_components(|&c| c == 'A');
+        let map = g.component_map(|&c| c == 'A');
+
+        for (id, region) in components.iter().enumerate() {
+            for &pos in region {
+                assert_eq!(map[pos], Some(id));
+            }
+        }
+        assert_eq!(map[Coordinate::new(2, 1)], None);
+    }
 }
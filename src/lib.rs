@@ -1,11 +1,18 @@
 //! Shared library for Advent of Code 2023.
 
 pub mod cli;
+pub mod coord3;
 pub mod coordinate;
+pub mod cursor;
+pub mod dijkstra;
 pub mod fsutils;
 pub mod grid;
 pub mod macros;
+pub mod parse;
+pub mod pathfind;
+pub mod solution;
 pub mod solutions;
+pub mod solver;
 
-pub use coordinate::{Coordinate, Direction};
+pub use coordinate::{Coordinate, Diagonal, Direction, Heading};
 pub use grid::Grid;
@@ -30,6 +30,20 @@ macro_rules! set {
     };
 }
 
+/// Embeds a day's real puzzle input into the binary at compile time, so it
+/// can be benchmarked or run without a filesystem path.
+///
+/// Resolves to `data/day<N>.txt` relative to the crate root. Gated behind
+/// the `embedded-input` feature, since puzzle inputs aren't committed by
+/// default.
+#[cfg(feature = "embedded-input")]
+#[macro_export]
+macro_rules! embed_input {
+    ($day:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/day", $day, ".txt"))
+    };
+}
+
 /// Creates a [`HashMap`][std::collections::HashMap] from a list of key-value
 /// pairs.
 ///
@@ -1,44 +1,177 @@
-use std::{process::ExitCode, time::Instant};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
 
-use anyhow::anyhow;
-use aoc::{cli::Cli, error, solutions};
+use anyhow::{anyhow, Result};
+use aoc::{
+    cli::Cli,
+    error,
+    solution::{self, Solution},
+    solutions,
+    solver::{self, Solver},
+};
 use clap::Parser;
 
+/// Days with a typed, timeable [`Solution`] or [`Solver`] implementation,
+/// for `--all`'s summary table.
+///
+/// Days not listed here still only expose a bespoke `exec` that prints
+/// straight to stdout with no per-part timing, so `--all` can't include them
+/// until they're migrated onto one of those traits.
+const ALL_DAYS: &[(i32, fn(&Path) -> Result<(Duration, Duration)>)] = &[
+    (1, time_solution::<solutions::day01::Day01>),
+    (2, time_solution::<solutions::day02::Day02>),
+    (3, time_solver::<solutions::day03::Day03>),
+    (4, time_solution::<solutions::day04::Day04>),
+    (6, time_solution::<solutions::day06::Day06>),
+];
+
 fn main() -> ExitCode {
     let args = Cli::parse();
-    let input = args
-        .input
-        .unwrap_or_else(|| format!("data/day{}.txt", { args.day }).into());
 
-    if !(input.exists() && input.is_file()) {
-        error!("input '{}' does not exist", input.display());
+    if args.all {
+        return run_all();
+    }
+
+    let day_spec = args.day.expect("day is required unless --all is given");
+    let is_range = day_spec.is_range();
+
+    if is_range && args.input.is_some() {
+        error!("--input can only be used when running a single day");
         return ExitCode::FAILURE;
     }
 
     let start = Instant::now();
-    let result = match args.day {
-        1 => solutions::day01::exec(input),
-        2 => solutions::day02::exec(input),
-        3 => solutions::day03::exec(input),
-        4 => solutions::day04::exec(input),
-        5 => solutions::day05::exec(input),
-        6 => solutions::day06::exec(input),
-        7 => solutions::day07::exec(input),
-        8 => solutions::day08::exec(input),
-        9 => solutions::day09::exec(input),
-        10 => solutions::day10::exec(input),
-        11 => solutions::day11::exec(input),
-        _ => Err(anyhow!("no solution found for day {}", args.day)),
-    };
+    let mut failed = false;
+
+    for day in day_spec.days() {
+        let input = args
+            .input
+            .clone()
+            .unwrap_or_else(|| format!("data/day{day}.txt").into());
+
+        if !(input.exists() && input.is_file()) {
+            error!("input '{}' does not exist", input.display());
+            failed = true;
+            continue;
+        }
+
+        if is_range {
+            println!("--- Day {day} ---");
+        }
+
+        // days registered in the `solver` registry are dispatched through the
+        // timing `Solver` runner; everything else goes through the generic
+        // `solution::exec` or its own day-specific `exec` function
+        let result = solver::run_day(day, &input, args.format).unwrap_or_else(|| match day {
+            1 => solution::exec::<solutions::day01::Day01>(input),
+            2 => solution::exec::<solutions::day02::Day02>(input),
+            5 => solutions::day05::exec(input),
+            6 => solution::exec::<solutions::day06::Day06>(input),
+            7 => solutions::day07::exec(input),
+            8 => solutions::day08::exec(input),
+            9 => solutions::day09::exec(input),
+            10 => solutions::day10::exec(input),
+            11 => solutions::day11::exec(input),
+            _ => Err(anyhow!("no solution found for day {day}")),
+        });
+
+        if let Err(err) = result {
+            error!("{err}");
+            failed = true;
+        }
+    }
 
     let elapsed = humantime::format_duration(start.elapsed());
     println!("\nTotal runtime: {elapsed}");
 
-    match result {
-        Ok(_) => ExitCode::SUCCESS,
-        Err(err) => {
-            error!("{err}");
-            ExitCode::FAILURE
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reads `S`'s part of `input` and times each one independently, discarding
+/// the computed answers -- only their timing matters here.
+fn time_solution<S: Solution>(input: &Path) -> Result<(Duration, Duration)> {
+    let contents = std::fs::read_to_string(input)?;
+
+    let start = Instant::now();
+    S::part1(&contents)?;
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    S::part2(&contents)?;
+    let part2 = start.elapsed();
+
+    Ok((part1, part2))
+}
+
+/// [`Solver`] counterpart to [`time_solution`].
+fn time_solver<S: Solver>(input: &Path) -> Result<(Duration, Duration)> {
+    let contents = std::fs::read_to_string(input)?;
+    let solver = S::parse(&contents)?;
+
+    let start = Instant::now();
+    solver.part1();
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    solver.part2();
+    let part2 = start.elapsed();
+
+    Ok((part1, part2))
+}
+
+/// Runs every day 1..=11 that has an entry in [`ALL_DAYS`], printing a
+/// Markdown-style `Day | Part 1 | Part 2` table plus a grand total.
+///
+/// Days without an entry are listed with `-` placeholders rather than
+/// silently omitted, so the table always accounts for the full 1..=11 range.
+fn run_all() -> ExitCode {
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| --- | --- | --- |");
+
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+
+    for day in 1..=11 {
+        let Some(&(_, time)) = ALL_DAYS.iter().find(|&&(d, _)| d == day) else {
+            println!("| {day} | - | - |");
+            continue;
+        };
+
+        let input: PathBuf = format!("data/day{day}.txt").into();
+        if !(input.exists() && input.is_file()) {
+            error!("input '{}' does not exist", input.display());
+            failed = true;
+            continue;
         }
+
+        match time(&input) {
+            Ok((part1, part2)) => {
+                total += part1 + part2;
+                println!(
+                    "| {day} | {} | {} |",
+                    humantime::format_duration(part1),
+                    humantime::format_duration(part2)
+                );
+            }
+            Err(err) => {
+                error!("day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
+
+    println!("\nGrand total: {}", humantime::format_duration(total));
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
     }
 }
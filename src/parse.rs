@@ -0,0 +1,293 @@
+//! A small token-stream cursor for hand-written parsers.
+//!
+//! Several parsers in this crate (triplet lists, prefixed sections, symbol
+//! grids) re-invent their own ad-hoc scanning over `&str` and tend to lose
+//! track of *where* in the input something went wrong -- a bad triplet gets
+//! silently dropped by a `filter_map`, or a bad line just panics on
+//! `.expect(...)`. [`Cursor`] tracks a byte offset plus 1-based line/column
+//! as it scans, so parsers built on top of it can report errors like
+//! "expected number at line 3, col 7" instead of failing silently or with no
+//! location at all.
+
+use std::fmt;
+
+/// A parse failure at a specific line/column in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at line {}, col {}", self.message, self.line, self.col)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A cursor over `&str` that tracks byte offset and 1-based line/column,
+/// for writing composable peek/consume parsers.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+    line: usize,
+    col: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            line: 1,
+            col: 1,
+        }
+    }
+
+    /// The 1-based line of the next unconsumed character.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The 1-based column of the next unconsumed character.
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// The byte offset of the next unconsumed character, for callers that
+    /// need a span into the original input rather than a line/column.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns whether the cursor has reached the end of the input.
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    /// Returns the next character without consuming it.
+    pub fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Consumes and returns the next character, advancing line/col.
+    pub fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+
+        Some(c)
+    }
+
+    /// Consumes characters while `pred` holds, returning the consumed slice.
+    pub fn consume_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if pred(c)) {
+            self.advance();
+        }
+
+        &self.input[start..self.pos]
+    }
+
+    /// Consumes runs of non-newline whitespace (spaces, tabs).
+    pub fn skip_whitespace(&mut self) {
+        self.consume_while(|c| c.is_whitespace() && c != '\n');
+    }
+
+    /// Consumes the rest of the current line, excluding the newline itself,
+    /// and any newline that terminates it.
+    pub fn consume_line(&mut self) -> &'a str {
+        let line = self.consume_while(|c| c != '\n');
+        self.advance();
+
+        line
+    }
+
+    /// Consumes exactly `literal`, or fails without consuming anything.
+    pub fn consume_literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.input[self.pos..].starts_with(literal) {
+            for _ in 0..literal.chars().count() {
+                self.advance();
+            }
+
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{literal}`")))
+        }
+    }
+
+    /// Skips leading whitespace, then consumes a run of ASCII digits as a
+    /// `u64`.
+    pub fn consume_u64(&mut self) -> Result<u64, ParseError> {
+        self.skip_whitespace();
+
+        let digits = self.consume_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(self.error("expected number"));
+        }
+
+        digits
+            .parse()
+            .map_err(|_| self.error(format!("number `{digits}` out of range")))
+    }
+
+    /// Consumes `"<prefix>:"`, then every whitespace-separated `u64` up to
+    /// the end of the line, e.g. `"Time:  7  15   30"` with `prefix`
+    /// `"Time"` yields `[7, 15, 30]`.
+    pub fn consume_prefixed_numbers(&mut self, prefix: &str) -> Result<Vec<u64>, ParseError> {
+        self.consume_literal(prefix)?;
+        self.consume_literal(":")?;
+
+        let mut nums = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some('\n') => break,
+                _ => nums.push(self.consume_u64()?),
+            }
+        }
+        self.advance();
+
+        Ok(nums)
+    }
+
+    /// Like [`Cursor::consume_prefixed_numbers`], but collapses every digit
+    /// up to the end of the line into a single `u64`, ignoring the
+    /// whitespace between them -- the "bad kerning" reading of a label
+    /// line, e.g. `"Time:      7  15   30"` yields `71530`.
+    pub fn consume_prefixed_kerned_number(&mut self, prefix: &str) -> Result<u64, ParseError> {
+        self.consume_literal(prefix)?;
+        self.consume_literal(":")?;
+
+        let mut digits = String::new();
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => digits.push(c),
+                Some(c) if c.is_whitespace() && c != '\n' => {}
+                _ => break,
+            }
+            self.advance();
+        }
+        self.advance();
+
+        if digits.is_empty() {
+            return Err(self.error("expected number"));
+        }
+
+        digits
+            .parse()
+            .map_err(|_| self.error(format!("number `{digits}` out of range")))
+    }
+
+    /// Builds a [`ParseError`] positioned at the cursor's current location.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            line: self.line,
+            col: self.col,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peek_and_advance_track_position() {
+        let mut c = Cursor::new("ab\ncd");
+        assert_eq!(c.peek(), Some('a'));
+        assert_eq!(c.advance(), Some('a'));
+        assert_eq!((c.line(), c.col()), (1, 2));
+        assert_eq!(c.advance(), Some('b'));
+        assert_eq!(c.advance(), Some('\n'));
+        assert_eq!((c.line(), c.col()), (2, 1));
+        assert_eq!(c.advance(), Some('c'));
+        assert_eq!((c.line(), c.col()), (2, 2));
+    }
+
+    #[test]
+    fn consume_while_stops_at_predicate() {
+        let mut c = Cursor::new("123abc");
+        assert_eq!(c.consume_while(|ch| ch.is_ascii_digit()), "123");
+        assert_eq!(c.consume_while(|ch| ch.is_ascii_digit()), "");
+    }
+
+    #[test]
+    fn consume_line_splits_on_newline() {
+        let mut c = Cursor::new("first\nsecond");
+        assert_eq!(c.consume_line(), "first");
+        assert_eq!(c.consume_line(), "second");
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn consume_literal_matches_or_fails() {
+        let mut c = Cursor::new("foo bar");
+        c.consume_literal("foo").unwrap();
+        assert_eq!(c.peek(), Some(' '));
+
+        let err = c.consume_literal("baz").unwrap_err();
+        assert_eq!(err.line, 1);
+        assert_eq!(err.col, 4);
+    }
+
+    #[test]
+    fn consume_u64_skips_leading_whitespace() {
+        let mut c = Cursor::new("  42 7");
+        assert_eq!(c.consume_u64().unwrap(), 42);
+        assert_eq!(c.consume_u64().unwrap(), 7);
+    }
+
+    #[test]
+    fn consume_u64_reports_position_on_failure() {
+        let mut c = Cursor::new("12 x");
+        c.consume_u64().unwrap();
+        c.skip_whitespace();
+        let err = c.consume_u64().unwrap_err();
+        assert_eq!((err.line, err.col), (1, 4));
+        assert_eq!(err.to_string(), "expected number at line 1, col 4");
+    }
+
+    #[test]
+    fn consume_prefixed_numbers_reads_whitespace_separated_list() {
+        let mut c = Cursor::new("Time:      7  15   30\nDistance:  9  40  200\n");
+        assert_eq!(c.consume_prefixed_numbers("Time").unwrap(), [7, 15, 30]);
+        assert_eq!(c.consume_prefixed_numbers("Distance").unwrap(), [9, 40, 200]);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn consume_prefixed_numbers_rejects_wrong_prefix() {
+        let mut c = Cursor::new("Distance: 9 40 200\n");
+        let err = c.consume_prefixed_numbers("Time").unwrap_err();
+        assert_eq!((err.line, err.col), (1, 1));
+    }
+
+    #[test]
+    fn pos_tracks_byte_offset() {
+        let mut c = Cursor::new("ab\ncd");
+        assert_eq!(c.pos(), 0);
+        c.advance();
+        c.advance();
+        assert_eq!(c.pos(), 2);
+        c.advance();
+        assert_eq!(c.pos(), 3);
+    }
+
+    #[test]
+    fn consume_prefixed_kerned_number_collapses_whitespace() {
+        let mut c = Cursor::new("Time:      7  15   30\nDistance:  9  40  200\n");
+        assert_eq!(c.consume_prefixed_kerned_number("Time").unwrap(), 71530);
+        assert_eq!(c.consume_prefixed_kerned_number("Distance").unwrap(), 940200);
+        assert!(c.is_empty());
+    }
+}
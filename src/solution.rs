@@ -0,0 +1,97 @@
+//! A typed day-solution interface.
+//!
+//! Unlike the ad-hoc `part1`/`part2` free functions sprinkled across the
+//! `dayN` modules (which just `println!` their result and return
+//! `Result<()>`), [`Solution`] has each part return a real, typed answer.
+//! That makes the answers directly testable and comparable across days,
+//! instead of only being checkable by eyeballing stdout.
+
+use std::{fmt::Display, path::Path};
+
+use anyhow::Result;
+
+/// A day's solution, with each part computed directly from the raw input
+/// text and returning a real, typed answer.
+pub trait Solution {
+    /// This solution's day number, for labeling output.
+    const DAY: u8;
+
+    /// The answer type returned by [`Solution::part1`].
+    type Answer1: Display;
+
+    /// The answer type returned by [`Solution::part2`].
+    type Answer2: Display;
+
+    /// Computes the answer to part 1 from the raw input text.
+    fn part1(input: &str) -> Result<Self::Answer1>;
+
+    /// Computes the answer to part 2 from the raw input text.
+    fn part2(input: &str) -> Result<Self::Answer2>;
+}
+
+/// A [`Solution`] that knows where to find its own input, so it can be run
+/// without the caller supplying a path.
+///
+/// Gated in practice behind the `embedded-input` feature: every current
+/// implementation returns [`crate::embed_input!`], and real puzzle inputs
+/// aren't committed to the repo.
+pub trait Problem: Solution {
+    /// Returns this day's embedded input text.
+    fn input() -> &'static str;
+}
+
+/// Reads `input`, runs both of `S`'s parts, and prints them in the
+/// `Part N: value` shape the original per-day `exec` functions used.
+///
+/// This is the path-based counterpart to [`run`]: it's for days that still
+/// read their input from disk via `main`, dispatched exactly like the
+/// bespoke `exec` functions they replace.
+pub fn exec<S: Solution>(input: impl AsRef<Path>) -> Result<()> {
+    let contents = std::fs::read_to_string(input)?;
+
+    println!("Part 1: {}", S::part1(&contents)?);
+    println!("Part 2: {}", S::part2(&contents)?);
+
+    Ok(())
+}
+
+/// A type-erased entry point for a [`Problem`], suitable for storing in
+/// [`REGISTRY`].
+#[cfg(feature = "embedded-input")]
+pub type Runner = fn() -> Result<()>;
+
+/// Reads `S`'s embedded input once, runs both parts, and times each
+/// independently.
+#[cfg(feature = "embedded-input")]
+pub fn run<S: Problem>() -> Result<()> {
+    let input = S::input();
+
+    let start = std::time::Instant::now();
+    let answer = S::part1(input)?;
+    println!(
+        "Day {} part 1: {answer} ({})",
+        S::DAY,
+        humantime::format_duration(start.elapsed())
+    );
+
+    let start = std::time::Instant::now();
+    let answer = S::part2(input)?;
+    println!(
+        "Day {} part 2: {answer} ({})",
+        S::DAY,
+        humantime::format_duration(start.elapsed())
+    );
+
+    Ok(())
+}
+
+/// Maps a day number to its [`Runner`], for days implementing [`Problem`].
+///
+/// Not every converted day is registered here yet -- add one once it
+/// implements [`Problem`].
+#[cfg(feature = "embedded-input")]
+pub static REGISTRY: &[(u8, Runner)] = &[
+    (2, run::<crate::solutions::day02::Day02>),
+    (4, run::<crate::solutions::day04::Day04>),
+    (13, run::<crate::solutions::day13::Day13>),
+];
@@ -0,0 +1,75 @@
+//! Solution for Advent of Code 2023, Day 1.
+//!
+//! # Day 1: Trebuchet?!
+//!
+//! Each line of the calibration document hides a two-digit number formed
+//! from its first and last digit. Part 1 only recognizes literal digits;
+//! part 2 also recognizes digits spelled out as words (`"one"`, `"two"`,
+//! etc.).
+
+mod decode;
+
+use anyhow::Result;
+
+pub use self::decode::{Decode, Mode};
+use crate::solution::Solution;
+
+/// Sums each line's two-digit calibration value, decoded in the given `mode`.
+fn sum_calibrations(input: &str, mode: Mode) -> u32 {
+    input.lines().map(|line| line.decode(mode)).sum()
+}
+
+/// [`Solution`] implementation for this day.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn part1(input: &str) -> Result<Self::Answer1> {
+        Ok(sum_calibrations(input, Mode::DigitOnly))
+    }
+
+    fn part2(input: &str) -> Result<Self::Answer2> {
+        Ok(sum_calibrations(input, Mode::DigitOrWord))
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+impl crate::solution::Problem for Day01 {
+    fn input() -> &'static str {
+        crate::embed_input!(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_DIGITS_ONLY: &str = "\
+        1abc2\n\
+        pqr3stu8vwx\n\
+        a1b2c3d4e5f\n\
+        treb7uchet\n";
+
+    static EXAMPLE_DIGITS_WORDS: &str = "\
+        two1nine\n\
+        eightwothree\n\
+        abcone2threexyz\n\
+        xtwone3four\n\
+        4nineeightseven2\n\
+        zoneight234\n\
+        7pqrstsixteen\n";
+
+    #[test]
+    fn day01_part1() {
+        assert_eq!(Day01::part1(EXAMPLE_DIGITS_ONLY).unwrap(), 142);
+    }
+
+    #[test]
+    fn day01_part2() {
+        assert_eq!(Day01::part2(EXAMPLE_DIGITS_WORDS).unwrap(), 281);
+    }
+}
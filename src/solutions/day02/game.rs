@@ -1,45 +1,141 @@
-use std::str::FromStr;
+use std::{cmp::Ordering, collections::BTreeMap, str::FromStr};
 
-use anyhow::{bail, Context};
+use anyhow::anyhow;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space0, space1, u32 as nom_u32},
+    combinator::all_consuming,
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::delimited,
+    Offset,
+};
 
-/// A sample of revealed cubes.
-#[derive(Debug, Default, PartialEq, Eq)]
+/// A sample of revealed cubes, as counts per color.
+///
+/// The color palette isn't fixed to red/green/blue: any color token the
+/// parser encounters gets its own entry, so this also works for puzzle
+/// variants or inputs with additional colors. This is already strictly more
+/// general than a fixed `[u32; 3]`/`Color` enum would be (see
+/// [`super::DEFAULT_COLORS`] for where the *standard* three colors are
+/// centralized instead), so it stays a map rather than an array indexed by
+/// a closed color set.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct Sample {
-    red: u32,
-    green: u32,
-    blue: u32,
+    counts: BTreeMap<String, u32>,
 }
 
 impl Sample {
-    pub fn new(red: u32, green: u32, blue: u32) -> Self {
-        Self { red, green, blue }
+    pub fn new<'a>(counts: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self {
+            counts: counts.into_iter().map(|(c, n)| (c.into(), n)).collect(),
+        }
+    }
+
+    /// Returns the revealed count for `color`, or `0` if this sample
+    /// doesn't mention it.
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
     }
 
     pub fn power(&self) -> u64 {
-        u64::from(self.red) * u64::from(self.green) * u64::from(self.blue)
+        self.counts.values().map(|&n| u64::from(n)).product()
     }
 }
 
+/// Compares two samples by cube-count containment: `self` is [`Ordering::Greater`]
+/// than `other` only if it has at least as many cubes of every color
+/// mentioned by either sample (a color absent from one side counts as `0`),
+/// and likewise [`Ordering::Less`] the other way around. Samples that have
+/// more of one color but less of another are incomparable (`None`).
+impl PartialOrd for Sample {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let colors = self.counts.keys().chain(other.counts.keys());
+
+        let mut at_least = true;
+        let mut at_most = true;
+        for color in colors {
+            match self.count(color).cmp(&other.count(color)) {
+                Ordering::Greater => at_most = false,
+                Ordering::Less => at_least = false,
+                Ordering::Equal => {}
+            }
+        }
+
+        match (at_least, at_most) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+            (false, false) => None,
+        }
+    }
+}
+
+type ParseResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Parses a single `<count> <color>` cube, tolerant of the whitespace
+/// between the number and the color name. Any alphabetic token is accepted
+/// as a color name, not just `red`/`green`/`blue`.
+fn cube(input: &str) -> ParseResult<'_, (u32, &str)> {
+    let (input, _) = space0(input)?;
+    let (input, n) = nom_u32(input)?;
+    let (input, _) = space1(input)?;
+    let (input, color) = context("color name", alpha1)(input)?;
+
+    Ok((input, (n, color)))
+}
+
+/// Parses a comma-separated list of cubes (a single revealed sample),
+/// tolerant of optional whitespace around the commas.
+fn sample(input: &str) -> ParseResult<'_, Sample> {
+    let (input, cubes) = separated_list1(delimited(space0, tag(","), space0), cube)(input)?;
+    let sample = Sample::new(cubes.into_iter().map(|(n, color)| (color, n)));
+
+    Ok((input, sample))
+}
+
+/// Locates `remaining` (a tail of `original`) as a 1-based `(line, col)`.
+fn locate(original: &str, remaining: &str) -> (usize, usize) {
+    let offset = original.offset(remaining);
+    let consumed = &original[..offset];
+    let line = consumed.matches('\n').count() + 1;
+    let col = match consumed.rfind('\n') {
+        Some(i) => offset - i,
+        None => offset + 1,
+    };
+
+    (line, col)
+}
+
+/// Turns a nom parse failure into an `anyhow::Error` that reports where in
+/// `original` parsing gave up, rather than panicking or failing silently.
+fn describe_error(original: &str, err: nom::Err<VerboseError<&str>>) -> anyhow::Error {
+    let e = match err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e,
+        nom::Err::Incomplete(_) => return anyhow!("unexpected end of input"),
+    };
+
+    let Some((remaining, kind)) = e.errors.last() else {
+        return anyhow!("failed to parse input");
+    };
+
+    let (line, col) = locate(original, remaining);
+    let message = match kind {
+        VerboseErrorKind::Context(ctx) => format!("expected {ctx}"),
+        _ => "malformed input".into(),
+    };
+    let found = remaining.lines().next().unwrap_or_default();
+
+    anyhow!("line {line}, col {col}: {message}, found `{found}`")
+}
+
 impl FromStr for Sample {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let mut sample = Self::default();
-        let splits = s.split(", ").map(|item| {
-            item.split_once(' ')
-                .expect("number and color name should be separated by a space")
-        });
-        for (n, color) in splits {
-            let num = n.parse::<u32>()?;
-            match color {
-                "red" => sample.red = num,
-                "green" => sample.green = num,
-                "blue" => sample.blue = num,
-                _ => bail!("unknown color `{}`", color),
-            };
-        }
-
-        Ok(sample)
+        all_consuming(sample)(s)
+            .map(|(_, sample)| sample)
+            .map_err(|e| describe_error(s, e))
     }
 }
 
@@ -55,38 +151,66 @@ impl Game {
         self.id
     }
 
-    pub fn is_possible(&self, sample: &Sample) -> bool {
-        self.samples
-            .iter()
-            .all(|s| sample.red >= s.red && sample.green >= s.green && sample.blue >= s.blue)
+    pub fn is_possible(&self, bag: &Sample) -> bool {
+        self.samples.iter().all(|s| bag >= s)
+    }
+
+    /// Returns every way this game's samples exceed `bag`'s limits.
+    ///
+    /// Each entry is `(sample index, offending color, revealed count, bag
+    /// limit)`; a sample that violates more than one color contributes one
+    /// entry per violated color.
+    pub fn violations(&self, bag: &Sample) -> Vec<(usize, String, u32, u32)> {
+        let mut out = vec![];
+        for (i, s) in self.samples.iter().enumerate() {
+            for (color, &revealed) in &s.counts {
+                let limit = bag.count(color);
+                if revealed > limit {
+                    out.push((i, color.clone(), revealed, limit));
+                }
+            }
+        }
+
+        out
     }
 
+    /// The power of the fewest cubes of each color that would make every
+    /// sample in this game possible: the per-color maximum revealed count
+    /// across all samples, multiplied together.
     pub fn power(&self) -> u64 {
-        let mut min_sample = Sample::default();
+        let mut max_counts: BTreeMap<String, u32> = BTreeMap::new();
         for sample in &self.samples {
-            min_sample.red = min_sample.red.max(sample.red);
-            min_sample.green = min_sample.green.max(sample.green);
-            min_sample.blue = min_sample.blue.max(sample.blue);
+            for (color, &n) in &sample.counts {
+                max_counts
+                    .entry(color.clone())
+                    .and_modify(|m| *m = (*m).max(n))
+                    .or_insert(n);
+            }
         }
 
-        min_sample.power()
+        Sample { counts: max_counts }.power()
     }
 }
 
+/// Parses a full `Game <id>: <sample>; <sample>; ...` line, tolerant of
+/// arbitrary inner whitespace around `:` and `;`.
+fn game(input: &str) -> ParseResult<'_, Game> {
+    let (input, _) = context("`Game` keyword", tag("Game"))(input)?;
+    let (input, _) = space1(input)?;
+    let (input, id) = context("game id", nom_u32)(input)?;
+    let (input, _) = delimited(space0, tag(":"), space0)(input)?;
+    let (input, samples) = separated_list1(delimited(space0, tag(";"), space0), sample)(input)?;
+
+    Ok((input, Game { id, samples }))
+}
+
 impl FromStr for Game {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let (prefix, samplestr) = s.split_once(": ").context("game line is not valid")?;
-        let (_, game) = prefix.split_once(' ').context("invalid game prefix")?;
-        let id = game.parse::<u32>()?;
-
-        let mut samples: Vec<Sample> = Vec::new();
-        for sample in samplestr.split("; ") {
-            samples.push(Sample::from_str(sample)?);
-        }
-
-        Ok(Game { id, samples })
+        all_consuming(game)(s)
+            .map(|(_, game)| game)
+            .map_err(|e| describe_error(s, e))
     }
 }
 
@@ -104,67 +228,36 @@ mod tests {
 
     #[test]
     fn parse_sample() {
-        let lines = vec!["3 blue, 4 red", "1 red, 2 green, 6 blue", "2 green"];
+        let lines = ["3 blue, 4 red", "1 red, 2 green, 6 blue", "2 green"];
         let expect_list = vec![
-            Sample {
-                red: 4,
-                green: 0,
-                blue: 3,
-            },
-            Sample {
-                red: 1,
-                green: 2,
-                blue: 6,
-            },
-            Sample {
-                red: 0,
-                green: 2,
-                blue: 0,
-            },
+            Sample::new([("blue", 3), ("red", 4)]),
+            Sample::new([("red", 1), ("green", 2), ("blue", 6)]),
+            Sample::new([("green", 2)]),
         ];
 
         for (&line, expected) in lines.iter().zip(expect_list) {
-            let game = Sample::from_str(line).unwrap();
-            assert_eq!(game, expected);
+            let sample = Sample::from_str(line).unwrap();
+            assert_eq!(sample, expected);
         }
     }
 
     #[test]
     fn sample_power() {
-        let sample = Sample {
-            red: 1,
-            green: 2,
-            blue: 6,
-        };
+        let sample = Sample::new([("red", 1), ("green", 2), ("blue", 6)]);
         assert_eq!(sample.power(), 12);
     }
 
     #[test]
     fn parse_games() {
-        let lines = vec!["Game 42: 15 blue", "Game 100: 2 green; 1 red"];
+        let lines = ["Game 42: 15 blue", "Game 100: 2 green; 1 red"];
         let expect_list = vec![
             Game {
                 id: 42,
-                samples: vec![Sample {
-                    red: 0,
-                    green: 0,
-                    blue: 15,
-                }],
+                samples: vec![Sample::new([("blue", 15)])],
             },
             Game {
                 id: 100,
-                samples: vec![
-                    Sample {
-                        red: 0,
-                        green: 2,
-                        blue: 0,
-                    },
-                    Sample {
-                        red: 1,
-                        green: 0,
-                        blue: 0,
-                    },
-                ],
+                samples: vec![Sample::new([("green", 2)]), Sample::new([("red", 1)])],
             },
         ];
 
@@ -177,15 +270,11 @@ mod tests {
     #[test]
     fn example_games_possible() {
         let possible = vec![true, true, false, false, true];
-        let sample = Sample {
-            red: 12,
-            green: 13,
-            blue: 14,
-        };
+        let bag = Sample::new([("red", 12), ("green", 13), ("blue", 14)]);
 
         for (&s, expected) in EXAMPLE_GAMES.iter().zip(possible) {
             let game = Game::from_str(s).unwrap();
-            assert_eq!(game.is_possible(&sample), expected);
+            assert_eq!(game.is_possible(&bag), expected);
         }
     }
 
@@ -198,4 +287,74 @@ mod tests {
             assert_eq!(game.power(), expected, "with game = {:?}", game);
         }
     }
+
+    #[test]
+    fn sample_partial_ord() {
+        let bag = Sample::new([("red", 12), ("green", 13), ("blue", 14)]);
+
+        // dominates in every color: comparable
+        assert!(bag >= Sample::new([("red", 1), ("green", 2), ("blue", 3)]));
+        assert!(Sample::new([("red", 1), ("green", 2), ("blue", 3)]) <= bag);
+        // equal samples compare equal
+        assert_eq!(
+            bag.partial_cmp(&Sample::new([("red", 12), ("green", 13), ("blue", 14)])),
+            Some(Ordering::Equal)
+        );
+        // more red but less green than the bag: incomparable
+        assert_eq!(
+            Sample::new([("red", 20), ("green", 1)]).partial_cmp(&bag),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_tolerates_irregular_whitespace() {
+        let expected = Game {
+            id: 42,
+            samples: vec![Sample::new([("blue", 3), ("red", 4)])],
+        };
+
+        for line in [
+            "Game 42: 3 blue, 4 red",
+            "Game   42:3 blue,4 red",
+            "Game 42 : 3 blue , 4 red",
+        ] {
+            assert_eq!(
+                Game::from_str(line).unwrap(),
+                expected,
+                "with line = {line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_accepts_arbitrary_color_tokens() {
+        let game = Game::from_str("Game 1: 4 purple, 2 chartreuse").unwrap();
+        let bag = Sample::new([("purple", 10), ("chartreuse", 10)]);
+
+        assert!(game.is_possible(&bag));
+        assert_eq!(game.power(), 8);
+    }
+
+    #[test]
+    fn parse_reports_malformed_cube() {
+        let err = Game::from_str("Game 1: 4 123").unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains("line 1, col 11"), "message was: {message}");
+        assert!(message.contains("color name"), "message was: {message}");
+    }
+
+    #[test]
+    fn game_violations() {
+        let bag = Sample::new([("red", 12), ("green", 13), ("blue", 14)]);
+        let game =
+            Game::from_str("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green").unwrap();
+
+        assert_eq!(game.violations(&bag), vec![(0, "red".to_string(), 20, 12)]);
+        assert!(Game::from_str("Game 1: 3 blue, 4 red")
+            .unwrap()
+            .violations(&bag)
+            .is_empty());
+    }
 }
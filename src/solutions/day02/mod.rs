@@ -0,0 +1,111 @@
+//! Solution for Advent of Code 2023, Day 2.
+//!
+//! # Day 2: Cube Conundrum
+//!
+//! The Elf reveals samples of colored cubes from a bag of unknown contents,
+//! once per round, across several games. Part 1 asks which games are
+//! possible with a fixed bag of 12 red, 13 green, and 14 blue cubes; part 2
+//! asks for the "power" of the fewest cubes that would make each game
+//! possible.
+
+mod game;
+
+use std::str::FromStr;
+
+pub use self::game::{Game, Sample};
+use crate::solution::Solution;
+
+/// The standard cube colors and their counts in the [`default_bag`].
+///
+/// `Sample` itself stays keyed on arbitrary color strings (see
+/// [`game::Sample`]'s doc comment), so this only centralizes the *default*
+/// bag's colors; adding or renaming one of these three is a one-line change
+/// here rather than edits scattered across string literals.
+static DEFAULT_COLORS: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// The default bag of cubes: 12 red, 13 green, 14 blue.
+fn default_bag() -> Sample {
+    Sample::new(DEFAULT_COLORS)
+}
+
+/// Reads the bag specification from the `AOC_BAG` environment variable
+/// (e.g. `"12 red, 13 green, 14 blue"`), falling back to [`default_bag`]
+/// when it isn't set.
+fn bag_from_env() -> anyhow::Result<Sample> {
+    match std::env::var("AOC_BAG") {
+        Ok(s) => Sample::from_str(&s),
+        Err(_) => Ok(default_bag()),
+    }
+}
+
+/// Parses one [`Game`] per line of `input`.
+fn parse_games(input: &str) -> anyhow::Result<Vec<Game>> {
+    input.lines().map(Game::from_str).collect()
+}
+
+/// [`Solution`] implementation for this day.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn part1(input: &str) -> anyhow::Result<Self::Answer1> {
+        let games = parse_games(input)?;
+        let bag = bag_from_env()?;
+        let verbose = std::env::var("AOC_VERBOSE").is_ok();
+
+        let mut sum = 0;
+        for game in &games {
+            let violations = game.violations(&bag);
+            if violations.is_empty() {
+                sum += game.id();
+            } else if verbose {
+                for (i, color, revealed, limit) in violations {
+                    println!(
+                        "Game {} impossible: sample {i} shows {revealed} {color} > {limit}",
+                        game.id()
+                    );
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(input: &str) -> anyhow::Result<Self::Answer2> {
+        let games = parse_games(input)?;
+        Ok(games.iter().map(Game::power).sum())
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+impl crate::solution::Problem for Day02 {
+    fn input() -> &'static str {
+        crate::embed_input!(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_GAMES: &str = "\
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n";
+
+    #[test]
+    fn day02_part1() {
+        assert_eq!(Day02::part1(EXAMPLE_GAMES).unwrap(), 8);
+    }
+
+    #[test]
+    fn day02_part2() {
+        assert_eq!(Day02::part2(EXAMPLE_GAMES).unwrap(), 2286);
+    }
+}
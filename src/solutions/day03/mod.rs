@@ -0,0 +1,49 @@
+//! Solution for Advent of Code 2023, Day 3.
+//!
+//! # Day 3: Gear Ratios
+//!
+//! The engine schematic is a grid of numbers, symbols, and `.` filler. Part
+//! 1 sums every number adjacent to a symbol (a "part number"); part 2 sums
+//! the product of each pair of numbers adjacent to a `*` that is adjacent to
+//! exactly two of them (a "gear").
+
+mod schematic;
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+pub use self::schematic::{Gear, Schematic};
+use crate::solver::Solver;
+
+/// [`Solver`] implementation for this day.
+pub struct Day03 {
+    schematic: Schematic,
+}
+
+impl Solver for Day03 {
+    const DAY: i32 = 3;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            schematic: Schematic::from_str(input)?,
+        })
+    }
+
+    fn part1(&self) -> String {
+        self.schematic
+            .part_numbers()
+            .iter()
+            .sum::<u64>()
+            .to_string()
+    }
+
+    fn part2(&self) -> String {
+        self.schematic
+            .gears()
+            .iter()
+            .map(Gear::ratio)
+            .sum::<u64>()
+            .to_string()
+    }
+}
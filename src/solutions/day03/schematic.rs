@@ -1,9 +1,12 @@
-use std::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use anyhow::bail;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Span {
+pub struct Span {
     line: usize,
     start: usize,
     end: usize,
@@ -35,7 +38,40 @@ impl Gear {
 pub struct Schematic {
     grid: Vec<Vec<char>>,
     numbers: Vec<Span>,
-    symbols: Vec<(usize, usize)>,
+    /// Maps each symbol coordinate to the indices of `numbers` adjacent to
+    /// it, built once in [`Schematic::from_str`] by [`build_index`] so that
+    /// [`Schematic::part_numbers`] and [`Schematic::symbol_clusters`] run in
+    /// roughly linear time instead of testing every symbol against every
+    /// span.
+    index: HashMap<(usize, usize), Vec<usize>>,
+}
+
+/// Builds the symbol-to-adjacent-span-indices index described on
+/// [`Schematic::index`]: each span is expanded to its neighbor cells once
+/// (columns `start-1..=end`, rows `line-1..=line+1`, matching
+/// [`Span::is_adjacent`]) and those cells are probed against the set of
+/// symbol coordinates, rather than testing every symbol against every span.
+fn build_index(
+    numbers: &[Span],
+    symbols: &[(usize, usize)],
+) -> HashMap<(usize, usize), Vec<usize>> {
+    let symbol_set: HashSet<(usize, usize)> = symbols.iter().copied().collect();
+    let mut index: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for (i, span) in numbers.iter().enumerate() {
+        let lo_x = span.start.saturating_sub(1);
+        let lo_y = span.line.saturating_sub(1);
+
+        for y in lo_y..=(span.line + 1) {
+            for x in lo_x..=span.end {
+                if symbol_set.contains(&(x, y)) {
+                    index.entry((x, y)).or_default().push(i);
+                }
+            }
+        }
+    }
+
+    index
 }
 
 impl Schematic {
@@ -48,61 +84,109 @@ impl Schematic {
         u64::from_str(&s).ok()
     }
 
-    fn is_part(&self, span: &Span) -> bool {
-        self.symbols.iter().any(|&(x, y)| span.is_adjacent(x, y))
+    /// The indices of `numbers` adjacent to at least one symbol, i.e. the
+    /// indices of part numbers.
+    fn part_span_indices(&self) -> HashSet<usize> {
+        self.index.values().flatten().copied().collect()
+    }
+
+    pub fn part_numbers(&self) -> Vec<u64> {
+        let parts = self.part_span_indices();
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| parts.contains(i))
+            .filter_map(|(_, s)| self.span_value(s))
+            .collect()
     }
 
-    fn parts(&self) -> Vec<&Span> {
-        self.numbers.iter().filter(|&s| self.is_part(s)).collect()
+    /// Returns every number span adjacent to the given coordinate.
+    pub fn numbers_adjacent_to(&self, x: usize, y: usize) -> Vec<&Span> {
+        self.numbers
+            .iter()
+            .filter(|s| s.is_adjacent(x, y))
+            .collect()
     }
 
-    pub fn part_numbers(&self) -> Vec<u64> {
-        self.parts()
+    /// Returns the part-number values adjacent to every occurrence of
+    /// `symbol` that is adjacent to exactly `n` numbers, using the
+    /// [`Schematic::index`] built once at parse time.
+    ///
+    /// This is the general form of the "gear" rule (a `*` adjacent to
+    /// exactly two part numbers): any symbol/count combination can be
+    /// queried without special-casing the symbol or the count in
+    /// [`Schematic`] itself. Results are ordered by symbol coordinate
+    /// (row-major) for a deterministic iteration order over the index.
+    pub fn symbol_clusters(&self, symbol: char, n: usize) -> Vec<Vec<u64>> {
+        let mut coords: Vec<&(usize, usize)> = self.index.keys().collect();
+        coords.sort_unstable_by_key(|&&(x, y)| (y, x));
+
+        coords
             .into_iter()
-            .filter_map(|s| self.span_value(s))
+            .filter(|&&(x, y)| self.at(x, y) == symbol && self.index[&(x, y)].len() == n)
+            .map(|&(x, y)| {
+                self.index[&(x, y)]
+                    .iter()
+                    .filter_map(|&i| self.span_value(&self.numbers[i]))
+                    .collect()
+            })
             .collect()
     }
 
     pub fn gears(&self) -> Vec<Gear> {
-        let mut list = vec![];
-        for &(x, y) in &self.symbols {
-            if self.at(x, y) != '*' {
-                continue;
-            }
-            let adjacent = self
-                .numbers
-                .iter()
-                .filter(|s| s.is_adjacent(x, y))
-                .collect::<Vec<_>>();
-            if adjacent.len() == 2 {
-                let p1 = self
-                    .span_value(adjacent[0])
-                    .expect("span should be a part number");
-                let p2 = self
-                    .span_value(adjacent[1])
-                    .expect("span should be a part number");
-                list.push(Gear { parts: [p1, p2] });
-            }
-        }
+        self.symbol_clusters('*', 2)
+            .into_iter()
+            .map(|parts| Gear {
+                parts: [parts[0], parts[1]],
+            })
+            .collect()
+    }
+
+    /// Parses a schematic's textual grid with a chosen [`SchematicParser`]
+    /// implementation, instead of always going through [`FromStr`]'s default
+    /// [`CharScanParser`].
+    pub fn parse_with<P: SchematicParser>(input: &str) -> anyhow::Result<Self> {
+        let (grid, numbers, symbols) = P::parse(input)?;
+        let index = build_index(&numbers, &symbols);
 
-        list
+        Ok(Self {
+            grid,
+            numbers,
+            index,
+        })
     }
 }
 
-impl FromStr for Schematic {
-    type Err = anyhow::Error;
+/// A pluggable front end for parsing a [`Schematic`]'s grid, number spans,
+/// and symbol coordinates from its textual representation.
+///
+/// [`Schematic::from_str`] uses [`CharScanParser`] by default; call
+/// [`Schematic::parse_with`] to choose a different implementation, such as
+/// [`RegexParser`] (gated behind the `regex-parser` feature).
+pub trait SchematicParser {
+    /// Parses `input` into the raw grid, number spans, and symbol
+    /// coordinates needed to build a [`Schematic`].
+    #[allow(clippy::type_complexity)]
+    fn parse(input: &str) -> anyhow::Result<(Vec<Vec<char>>, Vec<Span>, Vec<(usize, usize)>)>;
+}
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        if s.is_empty() {
+/// The original hand-written scanner: walks each line's `char_indices`,
+/// extending a number span while consecutive digits continue and recording
+/// any other non-`.` character as a symbol.
+pub struct CharScanParser;
+
+impl SchematicParser for CharScanParser {
+    fn parse(input: &str) -> anyhow::Result<(Vec<Vec<char>>, Vec<Span>, Vec<(usize, usize)>)> {
+        if input.is_empty() {
             bail!("grid data must have at least one line")
         }
 
         let mut grid = vec![];
-        let mut parts = vec![];
+        let mut numbers = vec![];
         let mut symbols = vec![];
 
         let mut span: Option<Span> = None;
-        for (j, line) in s.lines().enumerate() {
+        for (j, line) in input.lines().enumerate() {
             if line.is_empty() {
                 bail!("grid line must not be empty");
             }
@@ -124,7 +208,7 @@ impl FromStr for Schematic {
                 // complete the span if there is one tracked
                 if let Some(mut span) = span.take() {
                     span.end = i;
-                    parts.push(span);
+                    numbers.push(span);
                 }
                 // track symbol location regardless of current span
                 if c != '.' {
@@ -134,15 +218,68 @@ impl FromStr for Schematic {
             // a span can't cross lines, so complete the existing one
             if let Some(mut span) = span.take() {
                 span.end = width;
-                parts.push(span);
+                numbers.push(span);
             }
         }
 
-        Ok(Self {
-            grid,
-            numbers: parts,
-            symbols,
-        })
+        Ok((grid, numbers, symbols))
+    }
+}
+
+/// A regex-backed parser: locates every number with a single `\d+` pattern
+/// (taking each match's start/end directly as the [`Span`] bounds) instead
+/// of tracking a span by hand across consecutive digit characters.
+#[cfg(feature = "regex-parser")]
+pub struct RegexParser;
+
+#[cfg(feature = "regex-parser")]
+impl SchematicParser for RegexParser {
+    fn parse(input: &str) -> anyhow::Result<(Vec<Vec<char>>, Vec<Span>, Vec<(usize, usize)>)> {
+        if input.is_empty() {
+            bail!("grid data must have at least one line")
+        }
+
+        let digits = digit_pattern();
+        let mut grid = vec![];
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+
+        for (j, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                bail!("grid line must not be empty");
+            }
+            grid.push(line.chars().collect());
+
+            for m in digits.find_iter(line) {
+                numbers.push(Span {
+                    line: j,
+                    start: m.start(),
+                    end: m.end(),
+                });
+            }
+
+            for (i, c) in line.char_indices() {
+                if c != '.' && !c.is_ascii_digit() {
+                    symbols.push((i, j));
+                }
+            }
+        }
+
+        Ok((grid, numbers, symbols))
+    }
+}
+
+#[cfg(feature = "regex-parser")]
+fn digit_pattern() -> &'static regex::Regex {
+    static DIGITS: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    DIGITS.get_or_init(|| regex::Regex::new(r"\d+").expect("valid regex"))
+}
+
+impl FromStr for Schematic {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::parse_with::<CharScanParser>(s)
     }
 }
 
@@ -166,7 +303,9 @@ mod tests {
     fn parse_schematic() {
         let schematic = Schematic::from_str(SCHEMATIC_DATA).unwrap();
         assert_eq!(schematic.numbers.len(), 11);
-        assert_eq!(schematic.symbols.len(), 6);
+        // all 6 symbols in the example grid are adjacent to at least one
+        // number, so the index should have one bucket per symbol
+        assert_eq!(schematic.index.len(), 6);
     }
 
     #[test]
@@ -193,6 +332,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn schematic_numbers_adjacent_to() {
+        let schematic = Schematic::from_str(SCHEMATIC_DATA).unwrap();
+        // the `*` at (3, 1) is adjacent to 467 and 35
+        let adjacent = schematic.numbers_adjacent_to(3, 1);
+        let values = adjacent
+            .into_iter()
+            .filter_map(|s| schematic.span_value(s))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![467, 35]);
+    }
+
+    #[test]
+    fn schematic_symbol_clusters() {
+        let schematic = Schematic::from_str(SCHEMATIC_DATA).unwrap();
+        let clusters = schematic.symbol_clusters('*', 2);
+        assert_eq!(clusters, vec![vec![467, 35], vec![755, 598]]);
+        // no `#` symbol is adjacent to exactly two numbers
+        assert!(schematic.symbol_clusters('#', 2).is_empty());
+    }
+
     #[test]
     fn schematic_gears() {
         let nums = vec![Gear { parts: [467, 35] }, Gear { parts: [755, 598] }];
@@ -204,4 +364,14 @@ mod tests {
             assert_eq!(gear, expected);
         }
     }
+
+    #[cfg(feature = "regex-parser")]
+    #[test]
+    fn regex_parser_matches_char_scan_parser() {
+        let scan = Schematic::parse_with::<CharScanParser>(SCHEMATIC_DATA).unwrap();
+        let regex = Schematic::parse_with::<RegexParser>(SCHEMATIC_DATA).unwrap();
+
+        assert_eq!(scan.numbers, regex.numbers);
+        assert_eq!(scan.gears(), regex.gears());
+    }
 }
@@ -28,20 +28,87 @@ impl Card {
             1 << (count - 1)
         }
     }
+
+    /// Parses a single `Card <id>: <winning> | <numbers>` line with a
+    /// chosen [`CardParser`] implementation, instead of always going
+    /// through [`FromStr`]'s default [`SplitParser`].
+    pub fn parse_with<P: CardParser>(line: &str) -> anyhow::Result<Self> {
+        let (id, numbers, winning) = P::parse(line)?;
+
+        Ok(Self {
+            id,
+            numbers,
+            winning,
+        })
+    }
 }
 
-impl FromStr for Card {
-    type Err = anyhow::Error;
+/// A pile of scratchcards, resolving the won-copy cascade: each card's
+/// matches win one copy each of the next `matches()` cards, which may
+/// themselves win further copies.
+pub struct CardPile<'a> {
+    cards: &'a [Card],
+}
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let (prefix, body) = s.split_once(':').expect("line should have a `:` separator");
+impl<'a> CardPile<'a> {
+    /// Wraps `cards`, assumed ordered and contiguous by [`Card::id`] so that
+    /// "next `matches()` cards" and "next `matches()` positions" mean the
+    /// same thing.
+    pub fn new(cards: &'a [Card]) -> Self {
+        Self { cards }
+    }
+
+    /// Resolves the cascade and returns the total number of scratchcards
+    /// (originals and won copies).
+    ///
+    /// Propagates won copies forward through a `Vec<u64>` of copy-counts
+    /// indexed by position (seeded to 1): each card adds its current count
+    /// to the next `matches()` positions, clamped to the end of the slice.
+    /// This is a single forward pass, `O(n * m)` for `m` average matches.
+    pub fn total(&self) -> u64 {
+        let mut counts = vec![1u64; self.cards.len()];
+
+        for (i, card) in self.cards.iter().enumerate() {
+            let count = counts[i];
+            let hi = (i + 1 + card.matches() as usize).min(counts.len());
+
+            for slot in &mut counts[(i + 1)..hi] {
+                *slot += count;
+            }
+        }
+
+        counts.iter().sum()
+    }
+}
+
+/// A pluggable front end for extracting a card's id, numbers, and winning
+/// numbers from its line of input.
+///
+/// [`Card::from_str`] uses [`SplitParser`] by default; call
+/// [`Card::parse_with`] to choose a different implementation, such as
+/// [`RegexParser`] (gated behind the `regex-parser` feature).
+pub trait CardParser {
+    /// Parses `line` into `(id, numbers, winning)`.
+    fn parse(line: &str) -> anyhow::Result<(u64, Vec<u64>, HashSet<u64>)>;
+}
+
+/// The original hand-written parser: splits on `:` to separate the id from
+/// the body, then on `|` to separate the winning numbers from the numbers
+/// you have, and whitespace-splits each side.
+pub struct SplitParser;
+
+impl CardParser for SplitParser {
+    fn parse(line: &str) -> anyhow::Result<(u64, Vec<u64>, HashSet<u64>)> {
+        let (prefix, body) = line
+            .split_once(':')
+            .ok_or_else(|| anyhow!("line is missing a `:` separator"))?;
         let id = prefix
             .split_once(' ')
-            .ok_or_else(|| anyhow!("invalid prefix format"))
+            .ok_or_else(|| anyhow!("invalid card prefix format"))
             .map(|(_, id)| u64::from_str(id.trim()))??;
         let (winningstr, numstr) = body
             .split_once('|')
-            .ok_or_else(|| anyhow!("invalid prefix format"))?;
+            .ok_or_else(|| anyhow!("line is missing a `|` separator"))?;
         let winning = winningstr
             .split(' ')
             .filter(|&s| !s.is_empty())
@@ -53,11 +120,55 @@ impl FromStr for Card {
             .filter_map(|s| u64::from_str(s).ok())
             .collect();
 
-        Ok(Self {
-            id,
-            numbers,
-            winning,
-        })
+        Ok((id, numbers, winning))
+    }
+}
+
+/// A regex-backed parser: still splits on `:`/`|` to locate the id, winning
+/// numbers, and numbers you have, but extracts every number from each part
+/// with a single `\d+` pattern instead of whitespace-splitting and
+/// filtering out empty tokens.
+#[cfg(feature = "regex-parser")]
+pub struct RegexParser;
+
+#[cfg(feature = "regex-parser")]
+impl CardParser for RegexParser {
+    fn parse(line: &str) -> anyhow::Result<(u64, Vec<u64>, HashSet<u64>)> {
+        let digits = digit_pattern();
+        let (prefix, body) = line
+            .split_once(':')
+            .ok_or_else(|| anyhow!("line is missing a `:` separator"))?;
+        let id = digits
+            .find(prefix)
+            .ok_or_else(|| anyhow!("card prefix has no id"))
+            .map(|m| u64::from_str(m.as_str()))??;
+        let (winningstr, numstr) = body
+            .split_once('|')
+            .ok_or_else(|| anyhow!("line is missing a `|` separator"))?;
+        let winning = digits
+            .find_iter(winningstr)
+            .filter_map(|m| u64::from_str(m.as_str()).ok())
+            .collect();
+        let numbers = digits
+            .find_iter(numstr)
+            .filter_map(|m| u64::from_str(m.as_str()).ok())
+            .collect();
+
+        Ok((id, numbers, winning))
+    }
+}
+
+#[cfg(feature = "regex-parser")]
+fn digit_pattern() -> &'static regex::Regex {
+    static DIGITS: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    DIGITS.get_or_init(|| regex::Regex::new(r"\d+").expect("valid regex"))
+}
+
+impl FromStr for Card {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::parse_with::<SplitParser>(s)
     }
 }
 
@@ -141,4 +252,39 @@ mod tests {
             assert_eq!(card.points(), expected);
         }
     }
+
+    #[test]
+    fn card_pile_total_resolves_cascade() {
+        let cards = EXAMPLE_CARDS
+            .lines()
+            .map(|s| Card::from_str(s).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(CardPile::new(&cards).total(), 30);
+    }
+
+    #[test]
+    fn parse_rejects_missing_colon() {
+        let err = Card::from_str("Card 1 41 48 | 83 86").err().unwrap();
+        assert!(err.to_string().contains("`:`"), "message was: {err}");
+    }
+
+    #[test]
+    fn parse_rejects_missing_pipe() {
+        let err = Card::from_str("Card 1: 41 48 83 86 17").err().unwrap();
+        assert!(err.to_string().contains("`|`"), "message was: {err}");
+    }
+
+    #[cfg(feature = "regex-parser")]
+    #[test]
+    fn regex_parser_matches_split_parser() {
+        for line in EXAMPLE_CARDS.lines() {
+            let split = Card::parse_with::<SplitParser>(line).unwrap();
+            let regex = Card::parse_with::<RegexParser>(line).unwrap();
+
+            assert_eq!(split.id, regex.id);
+            assert_eq!(split.numbers, regex.numbers);
+            assert_eq!(split.winning, regex.winning);
+        }
+    }
 }
@@ -0,0 +1,144 @@
+//! Solution for Advent of Code 2023, Day 4.
+//!
+//! # Day 4: Scratchcards
+//!
+//! Each scratchcard lists winning numbers and the numbers you have; part 1
+//! sums each card's points (doubling per match after the first). Part 2
+//! treats matches as winning copies of the next `matches` cards, cascading
+//! until every original and won copy has been counted.
+
+mod card;
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+pub use self::card::{Card, CardPile};
+use crate::{solution::Solution, solver::Solver};
+
+/// Parses one [`Card`] per line of `input`, sorted by [`Card::id`] with
+/// contiguity validated up front so [`CardPile`] can map "next `matches()`
+/// cards" directly onto "next `matches()` positions".
+fn parse_cards(input: &str) -> Result<Vec<Card>> {
+    let mut cards = input
+        .lines()
+        .map(Card::from_str)
+        .collect::<Result<Vec<_>>>()?;
+    cards.sort_by_key(Card::id);
+    validate_contiguous_ids(&cards)?;
+
+    Ok(cards)
+}
+
+/// [`Solver`] implementation for this day.
+pub struct Day04 {
+    cards: Vec<Card>,
+}
+
+impl Solver for Day04 {
+    const DAY: i32 = 4;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            cards: parse_cards(input)?,
+        })
+    }
+
+    fn part1(&self) -> String {
+        self.cards.iter().map(Card::points).sum::<u64>().to_string()
+    }
+
+    /// Resolves the won-copy cascade via [`CardPile`] and sums the result.
+    fn part2(&self) -> String {
+        CardPile::new(&self.cards).total().to_string()
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn part1(input: &str) -> Result<Self::Answer1> {
+        let cards = parse_cards(input)?;
+        Ok(cards.iter().map(Card::points).sum())
+    }
+
+    fn part2(input: &str) -> Result<Self::Answer2> {
+        let cards = parse_cards(input)?;
+        Ok(CardPile::new(&cards).total())
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+impl crate::solution::Problem for Day04 {
+    fn input() -> &'static str {
+        crate::embed_input!(4)
+    }
+}
+
+/// Returns an error unless `cards` (assumed sorted by [`Card::id`]) has ids
+/// that are contiguous, since [`Day04::part2`]'s copies-propagation relies
+/// on "next `matches()` cards" and "next `matches()` positions" meaning the
+/// same thing.
+fn validate_contiguous_ids(cards: &[Card]) -> Result<()> {
+    let Some(base) = cards.first().map(Card::id) else {
+        return Ok(());
+    };
+
+    for (i, card) in cards.iter().enumerate() {
+        let expected = base + i as u64;
+        if card.id() != expected {
+            anyhow::bail!(
+                "card ids must be contiguous: expected id {expected}, found {}",
+                card.id()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n\
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n\
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n\
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n\
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11\n";
+
+    #[test]
+    fn day04_part1() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(), "13");
+    }
+
+    #[test]
+    fn day04_part2() {
+        let day = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(), "30");
+    }
+
+    #[test]
+    fn day04_solution_part1() {
+        assert_eq!(<Day04 as Solution>::part1(EXAMPLE).unwrap(), 13);
+    }
+
+    #[test]
+    fn day04_solution_part2() {
+        assert_eq!(<Day04 as Solution>::part2(EXAMPLE).unwrap(), 30);
+    }
+
+    #[test]
+    fn parse_rejects_sparse_ids() {
+        let input = "\
+            Card 1: 1 2 | 1 2\n\
+            Card 3: 1 2 | 1 2\n";
+        assert!(Day04::parse(input).is_err());
+    }
+}
@@ -1,8 +1,13 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 
 use super::seed::{Seed, SeedMode};
+use crate::parse::Cursor;
 
 trait Mapping {
     fn contains(&self, value: u64) -> bool;
@@ -12,6 +17,13 @@ trait Mapping {
     fn map(&self, value: u64) -> u64;
 
     fn invert(&self, value: u64) -> u64;
+
+    /// Maps a batch of half-open `[start, end)` intervals in one pass.
+    ///
+    /// This is the range-aware counterpart to [`Mapping::map`], letting a
+    /// single call move billions of values at once instead of mapping each
+    /// value individually.
+    fn map_range(&self, intervals: &[(u64, u64)]) -> Vec<(u64, u64)>;
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -43,26 +55,71 @@ impl Mapping for Bijection {
         assert!(self.range_contains(value));
         self.domain + (value - self.range)
     }
+
+    /// Maps the portion of each interval overlapping this bijection's domain,
+    /// passing through any non-overlapping portion unchanged.
+    fn map_range(&self, intervals: &[(u64, u64)]) -> Vec<(u64, u64)> {
+        let domain_end = self.domain + self.count;
+        // use a signed offset so `range < domain` doesn't underflow
+        let offset = self.range as i64 - self.domain as i64;
+
+        let mut out = Vec::with_capacity(intervals.len());
+        for &(start, end) in intervals {
+            if start >= end {
+                continue;
+            }
+
+            let overlap_start = start.max(self.domain);
+            let overlap_end = end.min(domain_end);
+            if overlap_start < overlap_end {
+                out.push((
+                    (overlap_start as i64 + offset) as u64,
+                    (overlap_end as i64 + offset) as u64,
+                ));
+                if start < overlap_start {
+                    out.push((start, overlap_start));
+                }
+                if overlap_end < end {
+                    out.push((overlap_end, end));
+                }
+            } else {
+                out.push((start, end));
+            }
+        }
+
+        out
+    }
+}
+
+impl Bijection {
+    /// Parses a `<range> <domain> <count>` triplet from a [`Cursor`],
+    /// leaving the cursor positioned just past the last number.
+    fn parse(cursor: &mut Cursor<'_>) -> std::result::Result<Self, crate::parse::ParseError> {
+        let range = cursor.consume_u64()?;
+        let domain = cursor.consume_u64()?;
+        let count = cursor.consume_u64()?;
+
+        Ok(Self {
+            domain,
+            range,
+            count,
+        })
+    }
 }
 
 impl FromStr for Bijection {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let nums = s
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .filter_map(|s| u64::from_str(s).ok())
-            .collect::<Vec<_>>();
-        if nums.len() != 3 {
-            bail!("bijection must contain exactly 3 numbers");
+        let mut cursor = Cursor::new(s);
+        let bijection = Self::parse(&mut cursor).map_err(|e| anyhow!("{e}"))?;
+
+        cursor.skip_whitespace();
+        if !cursor.is_empty() {
+            bail!("{}", cursor.error("unexpected trailing input after triplet"));
         }
 
-        Ok(Self {
-            domain: nums[1],
-            range: nums[0],
-            count: nums[2],
-        })
+        Ok(bijection)
     }
 }
 
@@ -97,29 +154,107 @@ impl Mapping for BijectionList {
         }
         value
     }
+
+    /// Maps a batch of intervals through every [`Bijection`] in this list.
+    ///
+    /// Each input interval is tested against every bijection's domain in
+    /// turn: a matching overlap is mapped and moved straight to the output,
+    /// while the unmatched remainder(s) are pushed back onto the worklist to
+    /// be tried against the remaining bijections. Anything left over after
+    /// all bijections have been tried passes through unchanged (identity).
+    fn map_range(&self, intervals: &[(u64, u64)]) -> Vec<(u64, u64)> {
+        let mut worklist = intervals.to_vec();
+        let mut out = Vec::with_capacity(intervals.len());
+
+        for b in &self.inner {
+            let mut remaining = Vec::with_capacity(worklist.len());
+            for (start, end) in worklist {
+                let domain_end = b.domain + b.count;
+                let overlap_start = start.max(b.domain);
+                let overlap_end = end.min(domain_end);
+
+                if overlap_start < overlap_end {
+                    out.extend(b.map_range(&[(overlap_start, overlap_end)]));
+                    if start < overlap_start {
+                        remaining.push((start, overlap_start));
+                    }
+                    if overlap_end < end {
+                        remaining.push((overlap_end, end));
+                    }
+                } else if start < end {
+                    remaining.push((start, end));
+                }
+            }
+            worklist = remaining;
+        }
+
+        // anything untouched by every bijection maps to itself
+        out.extend(worklist);
+        out
+    }
+}
+
+impl BijectionList {
+    /// Owned-input counterpart to [`Mapping::map_range`], named to match how
+    /// [`Almanac::find_min_location_ranges`] folds it across [`Almanac::chain`].
+    fn map_ranges(&self, inputs: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+        self.map_range(&inputs)
+    }
+}
+
+/// The category each stage maps from and to, e.g. `("seed", "soil")` for a
+/// `seed-to-soil map:` section.
+type CategoryEdge = (String, String);
+
+/// The category a chain of [`CategoryEdge`]s must start and end at.
+const START_CATEGORY: &str = "seed";
+const END_CATEGORY: &str = "location";
+
+/// A parse failure from [`Almanac::parse_verbose`], carrying the `[start,
+/// end)` byte span of the offending token in the original input instead of
+/// just a line/column -- [`Almanac::parse_with_mode`]'s `anyhow` errors are
+/// fine for a quick bail-out, but give a caller nothing to underline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseReport {
+    pub message: String,
+    pub span: (usize, usize),
+}
+
+impl fmt::Display for ParseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (bytes {}..{})", self.message, self.span.0, self.span.1)
+    }
+}
+
+impl std::error::Error for ParseReport {}
+
+/// The byte span `token` occupies within `haystack`, for [`ParseReport`].
+///
+/// `token` must be a substring slice of `haystack` (e.g. produced by
+/// [`Cursor`] or `str::trim`/`str::split_whitespace` over it), not merely an
+/// equal string, since this is a pointer-offset computation.
+fn span_of(haystack: &str, token: &str) -> (usize, usize) {
+    let start = token.as_ptr() as usize - haystack.as_ptr() as usize;
+    (start, start + token.len())
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Almanac {
     seeds: Vec<Seed>,
-    mappings: HashMap<String, BijectionList>,
+    mappings: HashMap<CategoryEdge, BijectionList>,
+    /// The `(from, to)` stages in traversal order from [`START_CATEGORY`] to
+    /// [`END_CATEGORY`], computed once by [`Almanac::build_chain`] so
+    /// `find_location`/`find_seed`/`find_min_location_ranges` don't have to
+    /// re-walk the category graph on every call.
+    chain: Vec<CategoryEdge>,
 }
 
 impl Almanac {
-    const MAP_NAMES: [&'static str; 7] = [
-        "seed-to-soil",
-        "soil-to-fertilizer",
-        "fertilizer-to-water",
-        "water-to-light",
-        "light-to-temperature",
-        "temperature-to-humidity",
-        "humidity-to-location",
-    ];
-
     fn new() -> Self {
         Self {
             seeds: Default::default(),
-            mappings: HashMap::from(Self::MAP_NAMES.map(|s| (s.into(), Default::default()))),
+            mappings: HashMap::new(),
+            chain: Vec::new(),
         }
     }
 
@@ -129,47 +264,211 @@ impl Almanac {
 
     pub fn parse_with_mode(s: &str, mode: SeedMode) -> Result<Self> {
         let mut result = Self::new();
+        let mut cursor = Cursor::new(s);
+        let mut section: Option<CategoryEdge> = None;
+
+        while !cursor.is_empty() {
+            let line_no = cursor.line();
+            let line = cursor.consume_line().trim();
+            if line.is_empty() {
+                continue;
+            }
 
-        let mut section = String::new();
-        for line in s.lines().filter(|&s| !s.is_empty()) {
             // parse seeds line
             if line.starts_with("seeds") {
                 result.seeds = Seed::parse_list(line, mode)?;
                 continue;
             }
 
-            // parse map section name
-            if line.ends_with("map:") {
-                section = line.strip_suffix(" map:").unwrap_or("").into();
-                if !Self::MAP_NAMES.contains(&section.as_str()) {
-                    bail!("invalid section name `{section}`");
-                }
+            // parse map section name, e.g. `seed-to-soil map:`
+            if let Some(name) = line.strip_suffix(" map:") {
+                let (from, to) = name
+                    .split_once("-to-")
+                    .ok_or_else(|| anyhow!("invalid section name `{name}` at line {line_no}"))?;
+                let edge = (from.to_string(), to.to_string());
+                result.mappings.entry(edge.clone()).or_default();
+                section = Some(edge);
+                continue;
+            }
+
+            // parse mapping triplet for the current section
+            let section = section
+                .as_ref()
+                .ok_or_else(|| anyhow!("mapping triplet at line {line_no} has no section"))?;
+            let b = Bijection::parse(&mut Cursor::new(line))
+                .map_err(|e| anyhow!("malformed mapping triplet at line {line_no}: {e}"))?;
+            result.mappings.get_mut(section).unwrap().inner.push(b);
+        }
+
+        result.chain = Self::build_chain(&result.mappings)?;
+
+        Ok(result)
+    }
+
+    /// [`Almanac::parse_with_mode`]'s diagnostic counterpart: same format,
+    /// but failures come back as a [`ParseReport`] carrying the byte span of
+    /// the offending token rather than a bare message, and every number is
+    /// validated individually instead of silently dropped on a parse
+    /// failure.
+    pub fn parse_verbose(s: &str, mode: SeedMode) -> std::result::Result<Self, ParseReport> {
+        let mut result = Self::new();
+        let mut cursor = Cursor::new(s);
+        let mut section: Option<CategoryEdge> = None;
+
+        while !cursor.is_empty() {
+            let line = cursor.consume_line().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let span = span_of(s, line);
+
+            // parse seeds line
+            if line.starts_with("seeds") {
+                let nums = line
+                    .split_once(':')
+                    .map(|(_, rest)| rest)
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .map(|tok| {
+                        tok.parse::<u64>().map_err(|_| ParseReport {
+                            message: format!("invalid seed number `{tok}`"),
+                            span: span_of(s, tok),
+                        })
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                result.seeds = match mode {
+                    SeedMode::List => nums.into_iter().map(Seed::from).collect(),
+                    SeedMode::RangePairs => {
+                        if nums.len() % 2 != 0 {
+                            return Err(ParseReport {
+                                message: "odd number of seed-range values".into(),
+                                span,
+                            });
+                        }
+                        nums.chunks_exact(2).map(Seed::from).collect()
+                    }
+                };
                 continue;
             }
 
-            // parse mapping triplet for current section
-            let b = Bijection::from_str(line)?;
-            result
-                .mappings
-                .entry(section.clone())
-                .and_modify(|x| x.inner.push(b));
+            // parse map section name, e.g. `seed-to-soil map:`
+            if let Some(name) = line.strip_suffix(" map:") {
+                let Some((from, to)) = name.split_once("-to-") else {
+                    return Err(ParseReport {
+                        message: format!("unknown section name `{name}`"),
+                        span,
+                    });
+                };
+                let edge = (from.to_string(), to.to_string());
+                result.mappings.entry(edge.clone()).or_default();
+                section = Some(edge);
+                continue;
+            }
+
+            // parse mapping triplet for the current section
+            let Some(section) = section.as_ref() else {
+                return Err(ParseReport {
+                    message: "mapping triplet has no section".into(),
+                    span,
+                });
+            };
+            let nums = line
+                .split_whitespace()
+                .map(|tok| {
+                    tok.parse::<u64>().map_err(|_| ParseReport {
+                        message: format!("invalid number `{tok}` in bijection"),
+                        span: span_of(s, tok),
+                    })
+                })
+                .collect::<std::result::Result<Vec<u64>, _>>()?;
+            if nums.len() != 3 {
+                return Err(ParseReport {
+                    message: "expected 3 numbers in bijection".into(),
+                    span,
+                });
+            }
+
+            result.mappings.get_mut(section).unwrap().inner.push(Bijection {
+                range: nums[0],
+                domain: nums[1],
+                count: nums[2],
+            });
         }
 
+        result.chain = Self::build_chain(&result.mappings).map_err(|e| ParseReport {
+            message: e.to_string(),
+            span: (s.len(), s.len()),
+        })?;
+
         Ok(result)
     }
 
+    /// Walks the `(from, to)` category graph discovered by [`Almanac::parse_with_mode`]
+    /// from [`START_CATEGORY`] to [`END_CATEGORY`], returning the stages in
+    /// traversal order.
+    ///
+    /// Errors if a category has no outgoing edge (a dangling chain) or if
+    /// following edges revisits a category (a cycle), rather than looping
+    /// forever.
+    fn build_chain(mappings: &HashMap<CategoryEdge, BijectionList>) -> Result<Vec<CategoryEdge>> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = START_CATEGORY.to_string();
+
+        while current != END_CATEGORY {
+            if !seen.insert(current.clone()) {
+                bail!("category graph has a cycle at `{current}`");
+            }
+
+            let edge = mappings
+                .keys()
+                .find(|(from, _)| *from == current)
+                .ok_or_else(|| anyhow!("category graph has no map starting from `{current}`"))?;
+            current = edge.1.clone();
+            chain.push(edge.clone());
+        }
+
+        Ok(chain)
+    }
+
     pub fn find_location(&self, seed: u64) -> u64 {
         let mut value = seed;
-        for key in Self::MAP_NAMES {
+        for key in &self.chain {
             value = self.mappings[key].map(value);
         }
 
         value
     }
 
+    /// Returns the lowest location number reachable from any seed range,
+    /// without brute-forcing every individual seed.
+    ///
+    /// This feeds the `[start, start+count)` interval of every [`Seed`]
+    /// through every stage of [`Almanac::chain`] as a batch, splitting
+    /// intervals only where a stage's bijections require it. The per-stage
+    /// output becomes the next stage's input.
+    pub fn find_min_location_ranges(&self) -> u64 {
+        let mut intervals = self
+            .seeds
+            .iter()
+            .map(|s| (s.start, s.start + s.count))
+            .collect::<Vec<_>>();
+
+        for key in &self.chain {
+            intervals = self.mappings[key].map_ranges(intervals);
+        }
+
+        intervals
+            .into_iter()
+            .map(|(start, _)| start)
+            .min()
+            .unwrap_or_default()
+    }
+
     pub fn find_seed(&self, location: u64) -> Option<u64> {
         let mut value = location;
-        for &key in Self::MAP_NAMES.iter().rev() {
+        for key in self.chain.iter().rev() {
             value = self.mappings[key].invert(value);
         }
 
@@ -240,43 +539,58 @@ mod tests {
         };
     }
 
+    fn category_edge(from: &str, to: &str) -> CategoryEdge {
+        (from.to_string(), to.to_string())
+    }
+
     fn example_almanac(mode: SeedMode) -> Almanac {
+        let chain = vec![
+            category_edge("seed", "soil"),
+            category_edge("soil", "fertilizer"),
+            category_edge("fertilizer", "water"),
+            category_edge("water", "light"),
+            category_edge("light", "temperature"),
+            category_edge("temperature", "humidity"),
+            category_edge("humidity", "location"),
+        ];
+
         let mut value = Almanac {
             seeds: vec![],
             mappings: map![
-                "seed-to-soil".into() => BijectionList { inner: vec![
+                category_edge("seed", "soil") => BijectionList { inner: vec![
                     bijection!(98, 50, 2),
                     bijection!(50, 52, 48),
                 ] },
-                "soil-to-fertilizer".into() => BijectionList { inner: vec![
+                category_edge("soil", "fertilizer") => BijectionList { inner: vec![
                     bijection!(15, 0, 37),
                     bijection!(52, 37, 2),
                     bijection!(0, 39, 15),
                 ] },
-                "fertilizer-to-water".into() => BijectionList { inner: vec![
+                category_edge("fertilizer", "water") => BijectionList { inner: vec![
                     bijection!(53, 49, 8),
                     bijection!(11, 0, 42),
                     bijection!(0, 42, 7),
                     bijection!(7, 57, 4),
                 ] },
-                "water-to-light".into() => BijectionList { inner: vec![
+                category_edge("water", "light") => BijectionList { inner: vec![
                     bijection!(18, 88, 7),
                     bijection!(25, 18, 70),
                 ] },
-                "light-to-temperature".into() => BijectionList { inner: vec![
+                category_edge("light", "temperature") => BijectionList { inner: vec![
                     bijection!(77, 45, 23),
                     bijection!(45, 81, 19),
                     bijection!(64, 68, 13),
                 ] },
-                "temperature-to-humidity".into() => BijectionList { inner: vec![
+                category_edge("temperature", "humidity") => BijectionList { inner: vec![
                     bijection!(69, 0, 1),
                     bijection!(0, 1, 69),
                 ] },
-                "humidity-to-location".into() => BijectionList { inner: vec![
+                category_edge("humidity", "location") => BijectionList { inner: vec![
                     bijection!(56, 60, 37),
                     bijection!(93, 56, 4),
                 ] },
             ],
+            chain,
         };
         if mode == SeedMode::List {
             value.seeds = vec![
@@ -404,4 +718,180 @@ mod tests {
         assert_eq!(a.find_seed(35).unwrap(), 13);
         assert_eq!(a.find_seed(0), None);
     }
+
+    #[test]
+    fn bijection_map_range() {
+        let b = Bijection {
+            domain: 50,
+            range: 52,
+            count: 48,
+        };
+
+        // fully inside the domain
+        assert_eq!(b.map_range(&[(60, 70)]), vec![(62, 72)]);
+        // fully outside the domain (identity)
+        assert_eq!(b.map_range(&[(0, 10)]), vec![(0, 10)]);
+        // straddling the domain boundary splits into matched + passthrough
+        assert_eq!(b.map_range(&[(40, 60)]), vec![(52, 62), (40, 50)]);
+    }
+
+    #[test]
+    fn bijection_list_map_range() {
+        let b = BijectionList {
+            inner: vec![
+                Bijection {
+                    domain: 98,
+                    range: 50,
+                    count: 2,
+                },
+                Bijection {
+                    domain: 50,
+                    range: 52,
+                    count: 48,
+                },
+            ],
+        };
+
+        let mut result = b.map_range(&[(79, 93), (55, 68)]);
+        result.sort();
+        assert_eq!(result, vec![(57, 70), (81, 95)]);
+    }
+
+    #[test]
+    fn almanac_find_min_location_ranges() {
+        let a = Almanac::parse_with_mode(EXAMPLE_ALMANAC, SeedMode::RangePairs).unwrap();
+        assert_eq!(a.find_min_location_ranges(), 46);
+    }
+
+    #[test]
+    fn bijection_list_map_ranges_matches_map_range() {
+        let b = BijectionList {
+            inner: vec![
+                Bijection {
+                    domain: 98,
+                    range: 50,
+                    count: 2,
+                },
+                Bijection {
+                    domain: 50,
+                    range: 52,
+                    count: 48,
+                },
+            ],
+        };
+        let inputs = vec![(79, 93), (55, 68)];
+
+        let mut via_ranges = b.map_ranges(inputs.clone());
+        let mut via_range = b.map_range(&inputs);
+        via_ranges.sort();
+        via_range.sort();
+
+        assert_eq!(via_ranges, via_range);
+    }
+
+    #[test]
+    fn almanac_discovers_chain_from_reordered_sections() {
+        // same example almanac, but with its sections reordered and renamed
+        // to non-standard category names -- the chain is still discovered by
+        // following `seed` to `location` through the `X-to-Y` headers
+        let almanac = "\
+            seeds: 79 14 55 13\n\
+            \n\
+            compost-to-location map:\n\
+            49 53 8\n\
+            0 11 42\n\
+            42 0 7\n\
+            57 7 4\n\
+            \n\
+            seed-to-dirt map:\n\
+            50 98 2\n\
+            52 50 48\n\
+            \n\
+            dirt-to-compost map:\n\
+            0 15 37\n\
+            37 52 2\n\
+            39 0 15\n";
+
+        let a = Almanac::parse_with_mode(almanac, SeedMode::List).unwrap();
+        assert_eq!(
+            a.chain,
+            vec![
+                category_edge("seed", "dirt"),
+                category_edge("dirt", "compost"),
+                category_edge("compost", "location"),
+            ]
+        );
+        assert_eq!(a.find_location(79), 81);
+    }
+
+    #[test]
+    fn almanac_parse_fails_on_dangling_chain() {
+        // `seed-to-soil` has no outgoing edge from `soil`, so the chain can
+        // never reach `location`
+        let almanac = "\
+            seeds: 79\n\
+            \n\
+            seed-to-soil map:\n\
+            50 98 2\n";
+
+        let err = Almanac::parse_with_mode(almanac, SeedMode::List).unwrap_err();
+        assert!(err.to_string().contains("no map starting from `soil`"));
+    }
+
+    #[test]
+    fn bijection_from_str_rejects_malformed_triplet() {
+        assert!(Bijection::from_str("50 98").is_err());
+        assert!(Bijection::from_str("50 98 2 7").is_err());
+        assert!(Bijection::from_str("50 98 x").is_err());
+    }
+
+    #[test]
+    fn almanac_parse_fails_loudly_on_malformed_triplet() {
+        let almanac = "\
+            seeds: 79 14\n\
+            \n\
+            seed-to-soil map:\n\
+            50 98 2\n\
+            52 50 oops\n";
+
+        let err = Almanac::parse_with_mode(almanac, SeedMode::List).unwrap_err();
+        assert!(err.to_string().contains("line 5"));
+    }
+
+    #[test]
+    fn parse_verbose_matches_parse_with_mode() {
+        let expected = Almanac::parse_with_mode(EXAMPLE_ALMANAC, SeedMode::List).unwrap();
+        let almanac = Almanac::parse_verbose(EXAMPLE_ALMANAC, SeedMode::List).unwrap();
+        assert_eq!(almanac, expected);
+    }
+
+    #[test]
+    fn parse_verbose_reports_span_of_malformed_triplet() {
+        let almanac = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 oops\n";
+        let report = Almanac::parse_verbose(almanac, SeedMode::List).unwrap_err();
+
+        assert_eq!(report.message, "invalid number `oops` in bijection");
+        let (start, end) = report.span;
+        assert_eq!(&almanac[start..end], "oops");
+    }
+
+    #[test]
+    fn parse_verbose_reports_odd_seed_range_count() {
+        let almanac = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n";
+        let report = Almanac::parse_verbose(almanac, SeedMode::RangePairs).unwrap_err();
+
+        assert_eq!(report.message, "odd number of seed-range values");
+        let (start, end) = report.span;
+        assert_eq!(&almanac[start..end], "seeds: 79 14 55");
+    }
+
+    #[test]
+    fn parse_verbose_reports_unknown_section_name() {
+        let almanac = "seeds: 79\n\nbogus map:\n50 98 2\n";
+        let report = Almanac::parse_verbose(almanac, SeedMode::List).unwrap_err();
+
+        assert_eq!(report.message, "unknown section name `bogus`");
+        let (start, end) = report.span;
+        assert_eq!(&almanac[start..end], "bogus map:");
+    }
 }
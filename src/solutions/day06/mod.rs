@@ -0,0 +1,64 @@
+//! Solution for Advent of Code 2023, Day 6.
+//!
+//! # Day 6: Wait For It
+//!
+//! Each race can be won by holding the button anywhere within a contiguous
+//! range of charge times. Part 1 multiplies together the number of winning
+//! charge times across several short races; part 2 treats the sheet's
+//! numbers as one much longer race with terrible kerning.
+
+mod race;
+
+use anyhow::Result;
+
+pub use self::race::{Format, Race};
+use crate::solution::Solution;
+
+/// [`Solution`] implementation for this day.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn part1(input: &str) -> Result<Self::Answer1> {
+        let races = Race::parse_format(input, Format::Multiple)?;
+        Ok(races.iter().fold(1, |value, race| value * race.margin()))
+    }
+
+    fn part2(input: &str) -> Result<Self::Answer2> {
+        let race = Race::parse_format(input, Format::Single)?
+            .into_iter()
+            .next()
+            .expect("single race format should yield exactly one race");
+        Ok(race.margin())
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+impl crate::solution::Problem for Day06 {
+    fn input() -> &'static str {
+        crate::embed_input!(6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE_RACES: &str = "\
+        Time:      7  15   30\n\
+        Distance:  9  40  200\n";
+
+    #[test]
+    fn day06_part1() {
+        assert_eq!(Day06::part1(EXAMPLE_RACES).unwrap(), 288);
+    }
+
+    #[test]
+    fn day06_part2() {
+        assert_eq!(Day06::part2(EXAMPLE_RACES).unwrap(), 71503);
+    }
+}
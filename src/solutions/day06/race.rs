@@ -1,6 +1,6 @@
-use std::str::FromStr;
+use anyhow::{anyhow, bail, Result};
 
-use anyhow::{bail, Result};
+use crate::parse::Cursor;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Format {
@@ -16,41 +16,37 @@ pub struct Race {
 
 impl Race {
     pub fn parse_format(s: &str, format: Format) -> Result<Vec<Self>> {
-        let mut times = None;
-        let mut distances = None;
+        let mut cursor = Cursor::new(s);
 
-        for line in s.lines() {
-            if !line.contains(':') {
-                bail!("incorrect race data format");
+        let result = match format {
+            Format::Multiple => {
+                let times = cursor
+                    .consume_prefixed_numbers("Time")
+                    .map_err(|e| anyhow!("{e}"))?;
+                let distances = cursor
+                    .consume_prefixed_numbers("Distance")
+                    .map_err(|e| anyhow!("{e}"))?;
+
+                if times.is_empty() || times.len() != distances.len() {
+                    bail!("`Time` and `Distance` lines must be equal-length, non-empty lists");
+                }
+
+                times
+                    .into_iter()
+                    .zip(distances)
+                    .map(|(t, d)| Race::new(t, d))
+                    .collect()
             }
-            let (prefix, numstr) = line
-                .split_once(':')
-                .expect("line should contain a ':' separator");
-
-            let nums = match format {
-                Format::Multiple => numstr
-                    .split(' ')
-                    .filter(|s| !s.is_empty())
-                    .map(u64::from_str)
-                    .collect::<Result<Vec<_>, _>>()?,
-                Format::Single => vec![u64::from_str(numstr.replace(' ', "").as_str())?],
-            };
-            match prefix {
-                "Time" => times = Some(nums),
-                "Distance" => distances = Some(nums),
-                _ => bail!("unknown data prefix `{}`", prefix),
-            };
-        }
-        if times.is_none() || distances.is_none() {
-            bail!("missing required data");
-        }
-        let t = times.expect("times should be some");
-        let d = distances.expect("distances should be some");
-        assert!(!t.is_empty() && t.len() == d.len());
+            Format::Single => {
+                let time = cursor
+                    .consume_prefixed_kerned_number("Time")
+                    .map_err(|e| anyhow!("{e}"))?;
+                let distance = cursor
+                    .consume_prefixed_kerned_number("Distance")
+                    .map_err(|e| anyhow!("{e}"))?;
 
-        let result = match format {
-            Format::Multiple => t.into_iter().zip(d).map(|(t, d)| Race::new(t, d)).collect(),
-            Format::Single => vec![Race::new(t[0], d[0])],
+                vec![Race::new(time, distance)]
+            }
         };
 
         Ok(result)
@@ -60,38 +56,46 @@ impl Race {
         Self { time, distance }
     }
 
-    fn can_win(&self, charge_ms: f64) -> bool {
-        let t = self.time as f64;
-        if charge_ms >= t {
+    fn can_win(&self, charge_ms: u64) -> bool {
+        if charge_ms >= self.time {
             return false;
         }
 
         // v * t > d
-        charge_ms * (t - charge_ms) > (self.distance as f64)
+        u128::from(charge_ms) * u128::from(self.time - charge_ms) > u128::from(self.distance)
     }
 
+    /// Computes the inclusive `(low, high)` charge times that beat the
+    /// record.
+    ///
+    /// `h*(time-h) > distance` is a downward parabola in `h`, so its real
+    /// roots bound the winning range; `disc` and the root arithmetic run in
+    /// `u128` so this stays exact across the full `u64` range of
+    /// `time`/`distance`, unlike an `f64` square root.
     fn win_condition(&self) -> (u64, u64) {
+        let time = u128::from(self.time);
+        let distance = u128::from(self.distance);
+
         // ensure quadratic solution will be real numbers
-        assert!((self.time * self.time) >= (4 * self.distance));
+        assert!(time * time >= 4 * distance);
 
-        let t = self.time as f64;
-        let d = self.distance as f64;
-        let s = ((t * t) - (4. * d)).sqrt();
-        let mut t_lo = ((t - s) / 2.).round();
-        let mut t_hi = ((t + s) / 2.).round();
+        let disc = (time * time) - (4 * distance);
+        let s = isqrt(disc);
+        let mut t_lo = ((time - s) / 2) as u64;
+        let mut t_hi = ((time + s + 1) / 2) as u64;
 
-        // fix rounding logic
+        // `s` is only the integer square root, so nudge each end onto the
+        // nearest charge time that actually wins
         if !self.can_win(t_lo) {
-            t_lo += 1.;
+            t_lo += 1;
         }
         if !self.can_win(t_hi) {
-            t_hi -= 1.;
+            t_hi -= 1;
         }
 
-        assert!(t_lo >= 0. && t_hi >= 0. && t_hi >= t_lo);
-        assert!(self.can_win(t_lo) && self.can_win(t_hi));
+        assert!(t_hi >= t_lo && self.can_win(t_lo) && self.can_win(t_hi));
 
-        (t_lo as u64, t_hi as u64)
+        (t_lo, t_hi)
     }
 
     pub fn margin(&self) -> u64 {
@@ -99,6 +103,35 @@ impl Race {
 
         t_hi + 1 - t_lo
     }
+
+    /// Counts winning charge times by direct simulation: `h*(time-h) > distance`
+    /// for every `h` in `1..time`.
+    ///
+    /// This is the straightforward per-charge approach [`Race::margin`]'s
+    /// quadratic `win_condition` replaces; it's `O(time)` instead of `O(1)`,
+    /// but it's a useful independent cross-check and benchmark baseline.
+    pub fn margin_bruteforce(&self) -> u64 {
+        (1..self.time)
+            .filter(|h| h * (self.time - h) > self.distance)
+            .count() as u64
+    }
+}
+
+/// Computes `floor(sqrt(n))` for `n >= 0` using Newton's method, with no
+/// floating point.
+fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+
+    x
 }
 
 #[cfg(test)]
@@ -134,13 +167,13 @@ mod tests {
         let race = Race::new(7, 9);
 
         for i in 0..2 {
-            assert!(!race.can_win(i as f64));
+            assert!(!race.can_win(i));
         }
         for i in 2..6 {
-            assert!(race.can_win(i as f64));
+            assert!(race.can_win(i));
         }
         for i in 6..10 {
-            assert!(!race.can_win(i as f64));
+            assert!(!race.can_win(i));
         }
     }
 
@@ -171,4 +204,55 @@ mod tests {
             assert_eq!(race.margin(), expected);
         }
     }
+
+    #[test]
+    fn isqrt_exact_and_rounded() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+
+        let max = u128::from(u64::MAX);
+        assert_eq!(isqrt(max * max), max);
+    }
+
+    #[test]
+    fn margin_holds_for_near_u64_max_race() {
+        // large enough that `time * time` overflows `u64`, but not `u128`
+        let race = Race::new(u64::MAX, u64::MAX / 4);
+        let (t_lo, t_hi) = race.win_condition();
+
+        assert!(race.can_win(t_lo) && race.can_win(t_hi));
+        assert!(!race.can_win(t_lo - 1) && !race.can_win(t_hi + 1));
+    }
+
+    #[test]
+    fn margin_matches_bruteforce_on_examples() {
+        let races = vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)];
+
+        for race in races {
+            assert_eq!(race.margin(), race.margin_bruteforce());
+        }
+    }
+
+    #[test]
+    fn margin_matches_bruteforce_on_fuzzed_pairs() {
+        // a tiny xorshift so this stays dependency-free and reproducible;
+        // kept small enough that `margin_bruteforce`'s O(time) loop is cheap
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..100 {
+            let time = next() % 500 + 1;
+            let distance = next() % (time * time / 4).max(1);
+            let race = Race::new(time, distance);
+
+            assert_eq!(race.margin(), race.margin_bruteforce(), "race: {race:?}");
+        }
+    }
 }
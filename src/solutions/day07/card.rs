@@ -1,9 +1,44 @@
-use anyhow::{anyhow, Result};
+use std::{cmp::Ordering, collections::HashSet};
+
+use anyhow::{anyhow, bail, Result};
+
+/// The largest face count a [`RuleSet`] can use: [`super::hand::Hand`]
+/// packs each card's value into a 4-bit sort-key field, so values (and thus
+/// face counts) must fit in `0..16`.
+pub(crate) const MAX_FACES: usize = 16;
+
+/// House rules for comparing and classifying a hand, decoupled from
+/// [`Hand::kind`][super::hand::Hand::kind] and [`Ord`] so new variants (e.g.
+/// an alternate wildcard set) don't require touching either.
+pub trait HandEvaluator {
+    /// Compares two cards' relative strength for tie-breaking.
+    fn cmp_card(&self, a: &Card, b: &Card) -> Ordering;
+
+    /// Adjusts a hand's per-face counts (indexed by [`Card::value`]) before
+    /// they're reduced to a [`HandKind`][super::hand::HandKind], e.g.
+    /// folding a wildcard face's count into the most frequent other face.
+    fn adjust_counts(&self, counts: &mut [u8]);
+}
+
+/// The default hand length every preset [`RuleSet`] (and [`RuleSet::from_spec`])
+/// uses; Camel Cards proper always deals 5 cards.
+const DEFAULT_HAND_LEN: usize = 5;
+
+/// The smallest hand length a [`RuleSet`] can declare: `classify` in
+/// [`super::hand`] assumes a three-of-a-kind or pair always leaves at least
+/// one more counted value to compare against (e.g. `3-1` vs. `3-2`), which
+/// only holds once a hand has at least 4 cards.
+pub(crate) const MIN_HAND_LEN: usize = 4;
+
+/// The largest hand length a [`RuleSet`] can declare: [`super::hand::Hand::sort_key`]
+/// packs a 4-bit `HandKind` plus a 4-bit field per card into a `u32`.
+pub(crate) const MAX_HAND_LEN: usize = 7;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RuleSet {
     faces: Vec<char>,
     wildcards: Vec<char>,
+    hand_len: usize,
 }
 
 impl RuleSet {
@@ -13,6 +48,7 @@ impl RuleSet {
                 '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
             ],
             wildcards: Vec::new(),
+            hand_len: DEFAULT_HAND_LEN,
         }
     }
 
@@ -22,6 +58,7 @@ impl RuleSet {
                 'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
             ],
             wildcards: vec!['J'],
+            hand_len: DEFAULT_HAND_LEN,
         }
     }
 
@@ -35,6 +72,75 @@ impl RuleSet {
     fn is_wild_face(&self, value: char) -> bool {
         self.wildcards.contains(&value)
     }
+
+    /// The number of distinct faces this ruleset recognizes, i.e. the
+    /// length a per-face count array passed to [`HandEvaluator::adjust_counts`]
+    /// must have.
+    pub(crate) fn num_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// The number of cards a [`super::hand::Hand`] parsed under this ruleset
+    /// must have.
+    pub(crate) fn hand_len(&self) -> usize {
+        self.hand_len
+    }
+
+    /// Parses a custom ruleset from a spec string: a face-ordering line
+    /// listing every card face from weakest to strongest (e.g.
+    /// `"23456789TJQKA"`), optionally followed by a `wild: <faces>` line
+    /// naming one or more of those faces as wildcards (e.g. `"wild: J, 2"`).
+    ///
+    /// This lets callers define custom decks -- reordering faces, or
+    /// declaring more than one wildcard -- without adding a new preset
+    /// constructor for each variant.
+    pub fn from_spec(s: &str) -> Result<Self> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        let faces: Vec<char> = lines
+            .next()
+            .ok_or_else(|| anyhow!("spec is missing a face-ordering line"))?
+            .chars()
+            .collect();
+        if faces.is_empty() {
+            bail!("face ordering must not be empty");
+        }
+        if faces.len() > MAX_FACES {
+            bail!("{} faces given, but at most {MAX_FACES} are supported", faces.len());
+        }
+
+        let mut seen = HashSet::new();
+        for &face in &faces {
+            if !seen.insert(face) {
+                bail!("duplicate face `{face}`");
+            }
+        }
+
+        let mut wildcards = Vec::new();
+        if let Some(wild_line) = lines.next() {
+            let rest = wild_line
+                .strip_prefix("wild:")
+                .ok_or_else(|| anyhow!("expected a `wild:` line, found `{wild_line}`"))?;
+
+            for token in rest.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                let mut chars = token.chars();
+                let (Some(face), None) = (chars.next(), chars.next()) else {
+                    bail!("wildcard `{token}` must be a single character");
+                };
+                if !faces.contains(&face) {
+                    bail!("wildcard `{face}` is not one of the declared faces");
+                }
+
+                wildcards.push(face);
+            }
+        }
+
+        Ok(Self {
+            faces,
+            wildcards,
+            hand_len: DEFAULT_HAND_LEN,
+        })
+    }
 }
 
 impl Default for RuleSet {
@@ -43,6 +149,114 @@ impl Default for RuleSet {
     }
 }
 
+/// Builds a [`RuleSet`] from its parts: a custom strength ordering, zero or
+/// more wildcard faces, and a hand length, validating the combination in
+/// [`RuleSetBuilder::build`] rather than on every [`Hand`][super::hand::Hand]
+/// parsed against it.
+///
+/// Unlike [`RuleSet::from_spec`] (a text format for the same thing), this is
+/// for callers building a ruleset programmatically, e.g. to experiment with
+/// a non-standard hand size or more than one wildcard rank.
+#[derive(Debug, Default)]
+pub struct RuleSetBuilder {
+    faces: Vec<char>,
+    wildcards: Vec<char>,
+    hand_len: Option<usize>,
+}
+
+impl RuleSetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the card strength ordering, weakest to strongest.
+    pub fn faces(mut self, faces: impl IntoIterator<Item = char>) -> Self {
+        self.faces = faces.into_iter().collect();
+        self
+    }
+
+    /// Marks `face` as wild. May be called more than once to declare
+    /// multiple wildcard ranks.
+    pub fn wild(mut self, face: char) -> Self {
+        self.wildcards.push(face);
+        self
+    }
+
+    /// Sets the number of cards a hand must have. Defaults to
+    /// [`DEFAULT_HAND_LEN`] if never called.
+    pub fn hand_len(mut self, hand_len: usize) -> Self {
+        self.hand_len = Some(hand_len);
+        self
+    }
+
+    pub fn build(self) -> Result<RuleSet> {
+        if self.faces.is_empty() {
+            bail!("face ordering must not be empty");
+        }
+        if self.faces.len() > MAX_FACES {
+            bail!(
+                "{} faces given, but at most {MAX_FACES} are supported",
+                self.faces.len()
+            );
+        }
+
+        let mut seen = HashSet::new();
+        for &face in &self.faces {
+            if !seen.insert(face) {
+                bail!("duplicate face `{face}`");
+            }
+        }
+        for &wild in &self.wildcards {
+            if !self.faces.contains(&wild) {
+                bail!("wildcard `{wild}` is not one of the declared faces");
+            }
+        }
+
+        let hand_len = self.hand_len.unwrap_or(DEFAULT_HAND_LEN);
+        if hand_len < MIN_HAND_LEN || hand_len > MAX_HAND_LEN {
+            bail!(
+                "hand length must be between {MIN_HAND_LEN} and {MAX_HAND_LEN}, got {hand_len}"
+            );
+        }
+
+        Ok(RuleSet {
+            faces: self.faces,
+            wildcards: self.wildcards,
+            hand_len,
+        })
+    }
+}
+
+impl HandEvaluator for RuleSet {
+    fn cmp_card(&self, a: &Card, b: &Card) -> Ordering {
+        a.value.cmp(&b.value)
+    }
+
+    /// Zeroes out every wildcard face's count and folds it into the most
+    /// frequent non-wildcard face (exactly the `values[0] += wilds` trick
+    /// this superseded), leaving `counts` untouched when this ruleset has no
+    /// wildcards.
+    fn adjust_counts(&self, counts: &mut [u8]) {
+        if self.wildcards.is_empty() {
+            return;
+        }
+
+        let mut wilds = 0u8;
+        for (i, face) in self.faces.iter().enumerate() {
+            if self.wildcards.contains(face) {
+                wilds += counts[i];
+                counts[i] = 0;
+            }
+        }
+
+        if wilds > 0 {
+            if let Some(max) = counts.iter_mut().max() {
+                *max += wilds;
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Card {
     pub(crate) value: usize,
@@ -65,3 +279,107 @@ impl From<usize> for Card {
         Self { value }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_spec_no_wildcards() {
+        let rules = RuleSet::from_spec("23456789TJQKA").unwrap();
+        assert_eq!(rules, RuleSet::standard());
+    }
+
+    #[test]
+    fn from_spec_with_wildcard() {
+        let rules = RuleSet::from_spec("J23456789TQKA\nwild: J").unwrap();
+        assert_eq!(rules, RuleSet::jokers_wild());
+    }
+
+    #[test]
+    fn from_spec_multiple_wildcards() {
+        let rules = RuleSet::from_spec("J23456789TQKA\nwild: J, 2").unwrap();
+        let two = Card::with_rules('2', &rules).unwrap();
+        let jack = Card::with_rules('J', &rules).unwrap();
+
+        assert!(rules.is_wild(&two));
+        assert!(rules.is_wild(&jack));
+    }
+
+    #[test]
+    fn from_spec_rejects_duplicate_face() {
+        assert!(RuleSet::from_spec("223456789TJQKA").is_err());
+    }
+
+    #[test]
+    fn from_spec_rejects_unknown_wildcard() {
+        assert!(RuleSet::from_spec("23456789TJQKA\nwild: X").is_err());
+    }
+
+    #[test]
+    fn from_spec_rejects_empty_faces() {
+        assert!(RuleSet::from_spec("").is_err());
+    }
+
+    #[test]
+    fn builder_defaults_match_standard() {
+        let rules = RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .build()
+            .unwrap();
+
+        assert_eq!(rules, RuleSet::standard());
+        assert_eq!(rules.hand_len(), DEFAULT_HAND_LEN);
+    }
+
+    #[test]
+    fn builder_supports_multiple_wilds_and_custom_hand_len() {
+        let rules = RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .wild('J')
+            .wild('2')
+            .hand_len(7)
+            .build()
+            .unwrap();
+
+        assert_eq!(rules.hand_len(), 7);
+        assert!(rules.is_wild(&Card::with_rules('J', &rules).unwrap()));
+        assert!(rules.is_wild(&Card::with_rules('2', &rules).unwrap()));
+        assert!(!rules.is_wild(&Card::with_rules('3', &rules).unwrap()));
+    }
+
+    #[test]
+    fn builder_rejects_hand_len_over_max() {
+        assert!(RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .hand_len(MAX_HAND_LEN + 1)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn builder_rejects_hand_len_under_min() {
+        // below `MIN_HAND_LEN`, a three- or two-of-a-kind can collapse to a
+        // single counted value and `classify` has nothing to compare it
+        // against -- reject it here instead of panicking later
+        for hand_len in 0..MIN_HAND_LEN {
+            assert!(
+                RuleSetBuilder::new()
+                    .faces("23456789TJQKA".chars())
+                    .hand_len(hand_len)
+                    .build()
+                    .is_err(),
+                "hand_len {hand_len} should have been rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn builder_rejects_wildcard_outside_faces() {
+        assert!(RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .wild('X')
+            .build()
+            .is_err());
+    }
+}
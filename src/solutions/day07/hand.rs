@@ -2,26 +2,36 @@ use std::{cmp::Ordering, str::FromStr};
 
 use anyhow::{anyhow, bail, Result};
 
-use crate::map;
-
-use super::card::{Card, RuleSet};
-
+use super::card::{Card, HandEvaluator, RuleSet, MAX_FACES};
+
+/// A hand's category, ordered weakest to strongest.
+///
+/// `Straight`, `Flush`, `StraightFlush` and `RoyalFlush` only apply to the suited [`super::poker`]
+/// evaluator; Camel Cards (this module) never produces them, and poker
+/// never produces `FiveKind` (a standard deck has no duplicate cards), so
+/// the two games' hands are never compared against each other even though
+/// they share one ordering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandKind {
     HighCard,
     OnePair,
     TwoPair,
     ThreeKind,
+    Straight,
+    Flush,
     FullHouse,
     FourKind,
+    StraightFlush,
+    RoyalFlush,
     FiveKind,
 }
 
 #[derive(Debug)]
 pub struct Hand {
-    cards: [Card; 5],
+    cards: Vec<Card>,
     bid: u64,
     rules: RuleSet,
+    kind: HandKind,
 }
 
 impl Hand {
@@ -35,18 +45,20 @@ impl Hand {
     }
 
     fn with_rules(s: &str, rules: &RuleSet) -> Result<Self> {
-        if s.len() != 5 {
-            bail!("hand must have exactly 5 cards");
+        if s.chars().count() != rules.hand_len() {
+            bail!("hand must have exactly {} cards", rules.hand_len());
         }
         let cards = s
             .chars()
             .map(|c| Card::with_rules(c, rules))
             .collect::<Result<Vec<_>, _>>()?;
+        let kind = classify(&cards, rules);
 
         Ok(Self {
-            cards: cards.try_into().expect("card vector should be valid array"),
+            cards,
             bid: 0,
             rules: rules.clone(),
+            kind,
         })
     }
 
@@ -62,53 +74,67 @@ impl Hand {
     }
 
     fn kind(&self) -> HandKind {
-        // get a count of each card type in the hand
-        let mut counts = map![];
-        let mut wilds = 0;
+        self.kind
+    }
+
+    /// Packs this hand's sort order into a single integer: `kind` in the
+    /// top bits, followed by each card's value in a 4-bit field, most
+    /// significant card first. Comparing two hands is then one `u32`
+    /// comparison instead of a kind check plus a per-card loop, and sorting
+    /// a slice of hands never recomputes `kind`.
+    ///
+    /// This assumes the ruleset's [`HandEvaluator::cmp_card`] agrees with
+    /// comparing [`Card::value`] directly, which holds for every
+    /// [`RuleSet`] built so far.
+    fn sort_key(&self) -> u32 {
+        let mut key = self.kind as u32;
         for card in &self.cards {
-            if self.rules.is_wild(card) {
-                wilds += 1;
-            } else {
-                counts.entry(card).and_modify(|c| *c += 1).or_insert(1);
-            }
+            key = (key << 4) | card.value as u32;
         }
 
-        // should not be possible, but account for a hand of all wildcards
-        if wilds >= 5 {
-            return HandKind::FiveKind;
-        }
+        key
+    }
+}
 
-        // collect and sort values highest to lowest. note, it doesn't matter
-        // what the cards actually are, just their counts
-        let mut values = counts.values().copied().collect::<Vec<_>>();
-        values.sort_by(|a, b| b.cmp(a));
-
-        // since this game does not have flushes or straights (e.g., hand value
-        // from sequential cards), jokers can only provide value by matching a
-        // card in the hand. it then follows that they can only provide the best
-        // value by matching the top counted card. by adding the wildcard count
-        // to highest (0-th) value, we can pick the category like any other hand
-        assert!(!values.is_empty());
-        values[0] += wilds;
-
-        // the highest counted value can quickly indicate what type of hand we have
-        match values[0] {
-            5 => HandKind::FiveKind,
-            4 => HandKind::FourKind,
-            3 => {
-                match values[1] {
-                    2 => HandKind::FullHouse, // 3 - 2
-                    _ => HandKind::ThreeKind, // 3 - 1 - 1
-                }
+/// Classifies a set of `cards` into a [`HandKind`] under `rules`.
+fn classify(cards: &[Card], rules: &RuleSet) -> HandKind {
+    // tally per-face counts, then hand them to the ruleset to fold in any
+    // wildcards (e.g. joker rules move a face's count onto the most
+    // frequent other face) before classification
+    let mut counts = [0u8; MAX_FACES];
+    for card in cards {
+        counts[card.value] += 1;
+    }
+    rules.adjust_counts(&mut counts[..rules.num_faces()]);
+
+    // collect and sort values highest to lowest. note, it doesn't matter
+    // what the cards actually are, just their counts
+    let mut values = counts.into_iter().filter(|&c| c > 0).collect::<Vec<_>>();
+    values.sort_by(|a, b| b.cmp(a));
+
+    // should not be possible for a 5-card hand, but guard against an
+    // all-zero count array rather than panicking on `values[0]`
+    if values.is_empty() {
+        return HandKind::HighCard;
+    }
+
+    // the highest counted value can quickly indicate what type of hand we have
+    match values[0] {
+        5 => HandKind::FiveKind,
+        4 => HandKind::FourKind,
+        3 => {
+            match values[1] {
+                2 => HandKind::FullHouse, // 3 - 2
+                _ => HandKind::ThreeKind, // 3 - 1 - 1
             }
-            2 => {
-                match values[1] {
-                    2 => HandKind::TwoPair, // 2 - 2 - 1
-                    _ => HandKind::OnePair, // 2 - 1 - 1 - 1
-                }
+        }
+        2 => {
+            match values[1] {
+                2 => HandKind::TwoPair, // 2 - 2 - 1
+                _ => HandKind::OnePair, // 2 - 1 - 1 - 1
             }
-            _ => HandKind::HighCard,
         }
+        _ => HandKind::HighCard,
     }
 }
 
@@ -128,33 +154,82 @@ impl PartialOrd for Hand {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        // hand kind takes priority
-        let k1 = self.kind();
-        let k2 = other.kind();
-        if k1 != k2 {
-            return k1.cmp(&k2);
-        }
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
 
-        // otherwise, check the first different card
-        for (c1, c2) in self.cards.iter().zip(other.cards.iter()) {
-            if c1 != c2 {
-                return c1.cmp(c2);
-            }
-        }
+/// Parses each of `hands` under `rules` and returns every input string tied
+/// for the best hand, preserving the original slices (not clones or
+/// indices) so callers can recover whatever metadata they attached to each
+/// hand string.
+///
+/// Returns `None` if `hands` is empty or any entry fails to parse; two
+/// hands are only tied when [`Hand::cmp`] reports them as truly equal (same
+/// kind, identical card sequence), not merely adjacent after a sort.
+pub fn winning_hands<'a>(hands: &[&'a str], rules: &RuleSet) -> Option<Vec<&'a str>> {
+    let parsed = hands
+        .iter()
+        .map(|&s| Hand::parse(s, rules).map(|hand| (s, hand)))
+        .collect::<Result<Vec<_>>>()
+        .ok()?;
+
+    let best = parsed.iter().map(|(_, hand)| hand).max()?;
+    Some(
+        parsed
+            .iter()
+            .filter(|(_, hand)| hand == best)
+            .map(|&(s, _)| s)
+            .collect(),
+    )
+}
+
+/// A full round of Camel Cards: every hand from a puzzle input, parsed
+/// under a single [`RuleSet`] and sorted ascending by strength so each
+/// hand's 1-based position is its rank.
+pub struct Game {
+    hands: Vec<Hand>,
+}
+
+impl Game {
+    /// Parses every line of `input` as a `cards bid` [`Hand`] under `rules`
+    /// and sorts the result ascending by strength.
+    pub fn parse(input: &str, rules: &RuleSet) -> Result<Self> {
+        let mut hands = input
+            .lines()
+            .map(|line| Hand::parse(line, rules))
+            .collect::<Result<Vec<_>>>()?;
+        hands.sort();
 
-        Ordering::Equal
+        Ok(Self { hands })
+    }
+
+    /// Each hand's 1-based rank (weakest first) paired with the hand
+    /// itself, in the same ascending order [`Game::parse`] sorted them --
+    /// lets callers spot-check individual ranks against the puzzle's
+    /// worked example.
+    pub fn ranks(&self) -> impl Iterator<Item = (u64, &Hand)> {
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i as u64 + 1, hand))
+    }
+
+    /// The puzzle's answer for this round: `Σ (rank * hand.bid())` across
+    /// every hand.
+    pub fn total_winnings(&self) -> u64 {
+        self.ranks().map(|(rank, hand)| rank * hand.bid()).sum()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::{super::card::RuleSetBuilder, *};
 
     #[test]
     fn hand_parse() {
         let s = "T55J5 684";
         let expected = Hand {
-            cards: [
+            cards: vec![
                 Card::from(8),
                 Card::from(3),
                 Card::from(3),
@@ -163,6 +238,7 @@ mod tests {
             ],
             bid: 684,
             rules: RuleSet::standard(),
+            kind: HandKind::ThreeKind,
         };
         let hand = Hand::parse(s, &RuleSet::standard()).unwrap();
 
@@ -173,7 +249,7 @@ mod tests {
     fn hand_parse_wild() {
         let s = "T55J5 684";
         let expected = Hand {
-            cards: [
+            cards: vec![
                 Card::from(9),
                 Card::from(4),
                 Card::from(4),
@@ -182,6 +258,7 @@ mod tests {
             ],
             bid: 684,
             rules: RuleSet::jokers_wild(),
+            kind: HandKind::FourKind,
         };
         let hand = Hand::parse(s, &RuleSet::jokers_wild()).unwrap();
 
@@ -206,6 +283,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hand_kind_is_cached_at_construction() {
+        // `kind()` just reads the field `with_rules` classified up front --
+        // confirm it agrees with classifying the same cards fresh, so a
+        // future change can't quietly start recomputing it in `cmp` again
+        let hand = Hand::new("KTJJT").unwrap();
+        assert_eq!(hand.kind, classify(&hand.cards, &hand.rules));
+    }
+
     #[test]
     fn hand_compare() {
         let high_card = Hand::new("23456").unwrap();
@@ -280,4 +366,99 @@ mod tests {
         hands.sort();
         assert_eq!(hands, expected);
     }
+
+    #[test]
+    fn winning_hands_single_best() {
+        let hands = ["32T3K 1", "T55J5 2", "KK677 3", "QQQJA 4"];
+        let winners = winning_hands(&hands, &RuleSet::standard()).unwrap();
+
+        assert_eq!(winners, vec!["QQQJA 4"]);
+    }
+
+    #[test]
+    fn winning_hands_ties() {
+        let hands = ["AAAA2 1", "AAAA2 2", "23456 3"];
+        let winners = winning_hands(&hands, &RuleSet::standard()).unwrap();
+
+        assert_eq!(winners, vec!["AAAA2 1", "AAAA2 2"]);
+    }
+
+    #[test]
+    fn winning_hands_rejects_malformed_input() {
+        assert!(winning_hands(&["not a hand"], &RuleSet::standard()).is_none());
+    }
+
+    #[test]
+    fn with_rules_supports_custom_hand_length_and_multiple_wilds() {
+        let rules = RuleSetBuilder::new()
+            .faces("234567890JQKA".chars())
+            .wild('J')
+            .wild('2')
+            .hand_len(7)
+            .build()
+            .unwrap();
+
+        let hand = Hand::with_rules("JJ22789", &rules).unwrap();
+        assert_eq!(hand.kind(), HandKind::FiveKind);
+    }
+
+    #[test]
+    fn with_rules_classifies_shortest_allowed_hand_len() {
+        // `MIN_HAND_LEN` (4) is the shortest length at which a three-of-a-kind
+        // still leaves a second counted value for `classify` to compare
+        // against (`values[1]`) -- regression test for a panic that used to
+        // be reachable with a shorter custom `hand_len`
+        let rules = RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .hand_len(4)
+            .build()
+            .unwrap();
+
+        let three_kind = Hand::with_rules("3334", &rules).unwrap();
+        assert_eq!(three_kind.kind(), HandKind::ThreeKind);
+
+        let two_pair = Hand::with_rules("2233", &rules).unwrap();
+        assert_eq!(two_pair.kind(), HandKind::TwoPair);
+    }
+
+    #[test]
+    fn with_rules_rejects_wrong_length_for_custom_hand_len() {
+        let rules = RuleSetBuilder::new()
+            .faces("23456789TJQKA".chars())
+            .hand_len(7)
+            .build()
+            .unwrap();
+
+        assert!(Hand::with_rules("23456", &rules).is_err());
+    }
+
+    static EXAMPLE_HANDS: &str = "\
+        32T3K 765\n\
+        T55J5 684\n\
+        KK677 28\n\
+        KTJJT 220\n\
+        QQQJA 483\n";
+
+    #[test]
+    fn game_total_winnings_standard() {
+        let game = Game::parse(EXAMPLE_HANDS, &RuleSet::standard()).unwrap();
+        assert_eq!(game.total_winnings(), 6440);
+    }
+
+    #[test]
+    fn game_total_winnings_jokers_wild() {
+        let game = Game::parse(EXAMPLE_HANDS, &RuleSet::jokers_wild()).unwrap();
+        assert_eq!(game.total_winnings(), 5905);
+    }
+
+    #[test]
+    fn game_ranks_match_sorted_order() {
+        let game = Game::parse(EXAMPLE_HANDS, &RuleSet::standard()).unwrap();
+        let ranks: Vec<(u64, u64)> = game.ranks().map(|(rank, hand)| (rank, hand.bid())).collect();
+
+        assert_eq!(
+            ranks,
+            vec![(1, 765), (2, 220), (3, 28), (4, 684), (5, 483)]
+        );
+    }
 }
@@ -0,0 +1,242 @@
+//! A real poker hand evaluator (unlike [`super::hand::Hand`], which only
+//! tracks rank and explicitly has no notion of flushes or straights).
+//!
+//! Cards here carry a suit, so [`HandKind`] gains `Straight`, `Flush`,
+//! `StraightFlush`, and `RoyalFlush` (the top case of `StraightFlush`, an
+//! ace-high straight flush). Camel Cards' `FiveKind` still sits above all of
+//! these in [`HandKind`]'s declared order, but the two games never actually
+//! compare hands against each other, so the relative placement doesn't
+//! matter functionally.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use anyhow::{anyhow, bail, Result};
+
+use super::hand::HandKind;
+
+/// A playing card's suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    fn parse(c: char) -> Result<Self> {
+        Ok(match c {
+            'C' => Self::Clubs,
+            'D' => Self::Diamonds,
+            'H' => Self::Hearts,
+            'S' => Self::Spades,
+            _ => bail!("unknown suit `{c}`"),
+        })
+    }
+}
+
+/// A single poker card: a rank (`2`-`9`, then `T`/`J`/`Q`/`K`/`A` as
+/// `10`-`14`) and a suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PokerCard {
+    pub rank: u8,
+    pub suit: Suit,
+}
+
+impl PokerCard {
+    /// Parses a rank+suit token, e.g. `AS` (ace of spades) or `TH` (ten of
+    /// hearts).
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        let rank = chars.next().ok_or_else(|| anyhow!("empty card token"))?;
+        let suit = chars
+            .next()
+            .ok_or_else(|| anyhow!("card token `{s}` is missing a suit"))?;
+        if chars.next().is_some() {
+            bail!("card token `{s}` is too long");
+        }
+
+        let rank = match rank {
+            '2'..='9' => rank.to_digit(10).expect("already matched a digit") as u8,
+            'T' => 10,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => bail!("unknown rank `{rank}`"),
+        };
+
+        Ok(Self {
+            rank,
+            suit: Suit::parse(suit)?,
+        })
+    }
+}
+
+/// A 5-card poker hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokerHand {
+    cards: [PokerCard; 5],
+}
+
+impl PokerHand {
+    /// Parses a whitespace-separated list of 5 rank+suit tokens, e.g.
+    /// `"AS KH QC JD TS"`.
+    pub fn parse(s: &str) -> Result<Self> {
+        let cards = s
+            .split_whitespace()
+            .map(PokerCard::parse)
+            .collect::<Result<Vec<_>>>()?;
+        let cards: [PokerCard; 5] = cards
+            .try_into()
+            .map_err(|_| anyhow!("poker hand must have exactly 5 cards"))?;
+
+        Ok(Self { cards })
+    }
+
+    /// Classifies this hand's [`HandKind`], accounting for flushes and
+    /// straights (including the ace-low "wheel", `A2345`, where the
+    /// effective high card is `5`, not the ace).
+    pub fn kind(&self) -> HandKind {
+        let ranks = self.sorted_ranks();
+        let flush = self.cards.windows(2).all(|w| w[0].suit == w[1].suit);
+        let straight = straight_high(&ranks);
+
+        if let Some(high) = straight {
+            if flush {
+                return if high == 14 {
+                    HandKind::RoyalFlush
+                } else {
+                    HandKind::StraightFlush
+                };
+            }
+        }
+
+        let groups = rank_groups(&ranks);
+        match (groups[0].1, groups.get(1).map(|&(_, n)| n).unwrap_or(0)) {
+            (4, _) => HandKind::FourKind,
+            (3, 2) => HandKind::FullHouse,
+            (3, _) => HandKind::ThreeKind,
+            (2, 2) => HandKind::TwoPair,
+            (2, _) => HandKind::OnePair,
+            _ if flush => HandKind::Flush,
+            _ if straight.is_some() => HandKind::Straight,
+            _ => HandKind::HighCard,
+        }
+    }
+
+    fn sorted_ranks(&self) -> [u8; 5] {
+        let mut ranks = self.cards.map(|c| c.rank);
+        ranks.sort_unstable();
+        ranks
+    }
+
+    /// This hand's comparison key: kind first, then the kicker ranks in
+    /// significance order (grouped by count, most frequent and highest
+    /// first), except straights/wheels which key on their effective high
+    /// card instead of raw rank.
+    fn sort_key(&self) -> (HandKind, Vec<u8>) {
+        let kind = self.kind();
+        let ranks = self.sorted_ranks();
+
+        let kicker = match kind {
+            HandKind::Straight | HandKind::StraightFlush | HandKind::RoyalFlush => {
+                vec![straight_high(&ranks).expect("kind implies a straight")]
+            }
+            _ => rank_groups(&ranks).into_iter().map(|(rank, _)| rank).collect(),
+        };
+
+        (kind, kicker)
+    }
+}
+
+impl PartialOrd for PokerHand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PokerHand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+/// Returns the straight's effective high card if `ranks` (sorted ascending)
+/// forms five consecutive ranks, handling the ace-low wheel (`A2345`).
+fn straight_high(ranks: &[u8; 5]) -> Option<u8> {
+    if ranks.windows(2).all(|w| w[1] == w[0] + 1) {
+        return Some(ranks[4]);
+    }
+    if *ranks == [2, 3, 4, 5, 14] {
+        return Some(5);
+    }
+
+    None
+}
+
+/// Groups `ranks` by rank, sorted by `(count, rank)` descending -- the
+/// standard "biggest group, then highest kicker" poker comparison order.
+fn rank_groups(ranks: &[u8; 5]) -> Vec<(u8, u8)> {
+    let mut counts: HashMap<u8, u8> = HashMap::new();
+    for &r in ranks {
+        *counts.entry(r).or_insert(0) += 1;
+    }
+
+    let mut groups: Vec<(u8, u8)> = counts.into_iter().collect();
+    groups.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_card() {
+        let card = PokerCard::parse("AS").unwrap();
+        assert_eq!(card.rank, 14);
+        assert_eq!(card.suit, Suit::Spades);
+    }
+
+    #[test]
+    fn kind_straight_flush() {
+        let hand = PokerHand::parse("9H TH JH QH KH").unwrap();
+        assert_eq!(hand.kind(), HandKind::StraightFlush);
+    }
+
+    #[test]
+    fn kind_royal_flush() {
+        let hand = PokerHand::parse("TH JH QH KH AH").unwrap();
+        assert_eq!(hand.kind(), HandKind::RoyalFlush);
+    }
+
+    #[test]
+    fn kind_wheel_straight() {
+        let hand = PokerHand::parse("AS 2D 3C 4H 5S").unwrap();
+        assert_eq!(hand.kind(), HandKind::Straight);
+    }
+
+    #[test]
+    fn kind_flush() {
+        let hand = PokerHand::parse("2H 5H 9H JH KH").unwrap();
+        assert_eq!(hand.kind(), HandKind::Flush);
+    }
+
+    #[test]
+    fn kind_four_of_a_kind() {
+        let hand = PokerHand::parse("9H 9D 9C 9S KH").unwrap();
+        assert_eq!(hand.kind(), HandKind::FourKind);
+    }
+
+    #[test]
+    fn wheel_ranks_below_six_high_straight() {
+        let wheel = PokerHand::parse("AS 2D 3C 4H 5S").unwrap();
+        let six_high = PokerHand::parse("2H 3D 4C 5H 6S").unwrap();
+
+        assert_eq!(wheel.kind(), HandKind::Straight);
+        assert_eq!(six_high.kind(), HandKind::Straight);
+        assert!(wheel < six_high);
+    }
+}
@@ -1,8 +1,44 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::FromStr,
+};
 
 use anyhow::anyhow;
 use num::Integer;
 
+/// A node's interned identifier: its name packed into a dense base-36
+/// integer by [`encode`], so stepping through the network is a single array
+/// index instead of a string hash lookup.
+type NodeId = u16;
+
+/// Every node name in this puzzle is exactly 3 base-36 characters
+/// (`0-9`, `A-Z`), so `NodeId` only ever needs `36^3` (46656) slots.
+const NAME_LEN: u32 = 3;
+
+/// Encodes a node name into its [`NodeId`]: each character is a base-36
+/// digit, most significant first.
+fn encode(name: &str) -> NodeId {
+    name.chars().fold(0u32, |acc, c| {
+        acc * 36 + c.to_digit(36).expect("node name must be base-36")
+    }) as NodeId
+}
+
+/// Decodes a [`NodeId`] produced by [`encode`] back into its name.
+fn decode(id: NodeId) -> String {
+    let mut id = id as u32;
+    let mut chars = vec!['0'; NAME_LEN as usize];
+    for c in chars.iter_mut().rev() {
+        *c = std::char::from_digit(id % 36, 36)
+            .expect("remainder of a division by 36 is always a valid base-36 digit")
+            .to_ascii_uppercase();
+        id /= 36;
+    }
+
+    chars.into_iter().collect()
+}
+
+/// A node as parsed straight off a line of puzzle input, before its name and
+/// edges are resolved into [`NodeId`]s by [`Network::from_str`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Node {
     name: String,
@@ -35,38 +71,156 @@ impl FromStr for Node {
     }
 }
 
+/// The set of steps at which a single ghost sits on a terminal node, as
+/// produced by [`Network::ghost_progressions`].
+///
+/// Once a ghost's `(node, instruction index)` state repeats, every hit from
+/// then on recurs every `lambda` steps, so it's represented as a congruence
+/// rather than enumerated; hits from before the cycle was entered have no
+/// such periodicity and are kept as single exact steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Progression {
+    /// A single hit in the simulation's non-repeating tail.
+    Exact(u64),
+    /// Every step `offset + k * lambda` for `k >= 0`.
+    Periodic { offset: u64, lambda: u64 },
+}
+
+impl Progression {
+    /// Combines two progressions into the smallest step satisfying both, or
+    /// `None` if they can never agree.
+    fn combine(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Progression::Exact(a), Progression::Exact(b)) => (a == b).then_some(self),
+            (Progression::Exact(x), Progression::Periodic { offset, lambda })
+            | (Progression::Periodic { offset, lambda }, Progression::Exact(x)) => {
+                (x >= offset && (x - offset) % lambda == 0).then_some(Progression::Exact(x))
+            }
+            (
+                Progression::Periodic {
+                    offset: o1,
+                    lambda: l1,
+                },
+                Progression::Periodic {
+                    offset: o2,
+                    lambda: l2,
+                },
+            ) => {
+                let (r, lcm) = crt(o1 as i128, l1 as i128, o2 as i128, l2 as i128)?;
+                let bound = o1.max(o2) as i128;
+                let offset = if r >= bound {
+                    r
+                } else {
+                    r + lcm * ((bound - r + lcm - 1) / lcm)
+                };
+                Some(Progression::Periodic {
+                    offset: offset as u64,
+                    lambda: lcm as u64,
+                })
+            }
+        }
+    }
+
+    /// The smallest step this progression represents.
+    fn min_step(self) -> u64 {
+        match self {
+            Progression::Exact(x) => x,
+            Progression::Periodic { offset, .. } => offset,
+        }
+    }
+}
+
+/// Combines `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)` into a single congruence
+/// `x ≡ r (mod lcm(m1, m2))` via the extended Euclidean algorithm, or `None`
+/// if the two are inconsistent (`r1 != r2 (mod gcd(m1, m2))`).
+fn crt(r1: i128, m1: i128, r2: i128, m2: i128) -> Option<(i128, i128)> {
+    let egcd = m1.extended_gcd(&m2);
+    let g = egcd.gcd;
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+
+    let lcm = m1 / g * m2;
+    let diff = (r2 - r1) / g;
+    let x = r1 + m1 * (egcd.x * diff).rem_euclid(m2 / g);
+
+    Some((x.rem_euclid(lcm), lcm))
+}
+
+/// A start node's cycle structure under a fixed instruction sequence, as
+/// detected by [`Network::analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleInfo {
+    /// Length of the non-repeating tail before the `(node, instruction
+    /// index)` state first repeats.
+    pub tail_len: u64,
+    /// Length of the repeating cycle entered after the tail.
+    pub cycle_len: u64,
+    /// Every step (from 0) at which the simulation sits on a node ending in
+    /// the `to_suffix` passed to [`Network::analyze`].
+    pub hits: Vec<u64>,
+}
+
 #[derive(Debug)]
 pub struct Network {
-    map: HashMap<String, Node>,
+    /// `(left, right)` ids indexed by node id; `None` where no node with
+    /// that id was defined in the puzzle input.
+    adjacency: Vec<Option<(NodeId, NodeId)>>,
+    /// Every id that was actually defined, in parse order.
+    ids: Vec<NodeId>,
     instructions: String,
 }
 
 impl Network {
-    /// Returns the left or right [`Node`] based on given name and instruction.
-    fn step(&self, name: &str, instruction: char) -> &Node {
-        let node = &self.map[name];
+    /// Returns the left or right neighbor id of `id` based on `instruction`.
+    fn step(&self, id: NodeId, instruction: char) -> NodeId {
+        let (left, right) = self.adjacency[id as usize].expect("unknown node id");
         match instruction {
-            'L' => &self.map[&node.left],
-            'R' => &self.map[&node.right],
+            'L' => left,
+            'R' => right,
             _ => panic!("unknown instruction"),
         }
     }
 
-    /// Counts the number of steps it takes to move from the starting node until
-    /// the predicate `f` returns `true`.
+    /// Every defined node id whose decoded name ends with `suffix`, computed
+    /// once per call so the hot stepping loops below only need an id-set
+    /// lookup instead of a string comparison on every step.
+    fn ids_ending_with(&self, suffix: &str) -> HashSet<NodeId> {
+        self.ids
+            .iter()
+            .copied()
+            .filter(|&id| decode(id).ends_with(suffix))
+            .collect()
+    }
+
+    /// Counts the number of steps it takes to move from the starting node
+    /// until its name satisfies the predicate `f`.
     pub fn steps<F>(&self, from: &str, f: F) -> u64
     where
-        F: Fn(&Node) -> bool,
+        F: Fn(&str) -> bool,
     {
-        assert!(self.map.contains_key(from));
+        let mut inst = self.instructions.chars().cycle();
+        let mut current = encode(from);
+        let mut count = 0;
+
+        while !f(&decode(current)) {
+            count += 1;
+            current = self.step(current, inst.next().unwrap());
+        }
+
+        count
+    }
 
+    /// Steps from `start` until it lands on a node whose id is in
+    /// `terminal`.
+    fn steps_to(&self, start: NodeId, terminal: &HashSet<NodeId>) -> u64 {
         let mut inst = self.instructions.chars().cycle();
-        let mut current = &self.map[from];
+        let mut current = start;
         let mut count = 0;
 
-        while !f(current) {
+        while !terminal.contains(&current) {
             count += 1;
-            current = self.step(&current.name, inst.next().unwrap());
+            current = self.step(current, inst.next().unwrap());
         }
 
         count
@@ -85,12 +239,151 @@ impl Network {
     /// steps from `__A` to `__Z` and then get the Least Common Multiple (LCM)
     /// of all the paths to get a final answer.
     pub fn steps_parallel(&self, from_suffix: &str, to_suffix: &str) -> u64 {
-        self.map
-            .keys()
-            .filter(|&k| k.ends_with(from_suffix))
-            .map(|n| self.steps(n, |n| n.name.ends_with(to_suffix)))
+        let terminal = self.ids_ending_with(to_suffix);
+        self.ids_ending_with(from_suffix)
+            .into_iter()
+            .map(|id| self.steps_to(id, &terminal))
             .fold(1, |value, n| n.lcm(&value))
     }
+
+    /// Builds a reverse-edge map: for each node id, the ids of nodes whose
+    /// `left` or `right` edge points to it.
+    fn predecessors(&self) -> HashMap<NodeId, Vec<NodeId>> {
+        let mut pred: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+
+        for &id in &self.ids {
+            let (left, right) = self.adjacency[id as usize].expect("defined id has edges");
+            pred.entry(left).or_default().push(id);
+            pred.entry(right).or_default().push(id);
+        }
+
+        pred
+    }
+
+    /// Returns every node (including `target` itself) from which `target`
+    /// is reachable under some instruction sequence, via a BFS over the
+    /// reverse `left`/`right` edges built by [`Network::predecessors`].
+    ///
+    /// This lets callers prune nodes that can never reach a given target
+    /// (e.g. a sink like `XXX = (XXX, XXX)`) before running
+    /// [`Network::steps`] or [`Network::steps_parallel`].
+    pub fn reaches(&self, target: &str) -> HashSet<String> {
+        let pred = self.predecessors();
+
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([encode(target)]);
+
+        while let Some(id) = queue.pop_front() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            queue.extend(pred.get(&id).into_iter().flatten().copied());
+        }
+
+        visited.into_iter().map(decode).collect()
+    }
+
+    /// Simulates from `start`, keyed on `(node, instruction index)`, until a
+    /// state repeats, and returns the resulting [`CycleInfo`]: the tail and
+    /// cycle lengths, and every step at which the simulation sits on a node
+    /// ending in `to_suffix`.
+    pub fn analyze(&self, start: &str, to_suffix: &str) -> CycleInfo {
+        self.analyze_from(encode(start), &self.ids_ending_with(to_suffix))
+    }
+
+    /// Core of [`Network::analyze`], taking an already-resolved start id and
+    /// a precomputed terminal-id set so callers looping over many ghosts
+    /// (like [`Network::steps_parallel_general`]) only pay for the suffix
+    /// decoding once, not once per ghost.
+    fn analyze_from(&self, start: NodeId, terminal: &HashSet<NodeId>) -> CycleInfo {
+        let inst_len = self.instructions.len();
+        let mut inst = self.instructions.chars().cycle();
+        let mut seen: HashMap<(NodeId, usize), u64> = HashMap::new();
+        let mut hits = vec![];
+
+        let mut current = start;
+        let mut inst_idx = 0;
+        let mut step = 0u64;
+
+        let (tail_len, cycle_len) = loop {
+            if let Some(&first) = seen.get(&(current, inst_idx)) {
+                break (first, step - first);
+            }
+            seen.insert((current, inst_idx), step);
+
+            if terminal.contains(&current) {
+                hits.push(step);
+            }
+
+            current = self.step(current, inst.next().unwrap());
+            inst_idx = (inst_idx + 1) % inst_len;
+            step += 1;
+        };
+
+        CycleInfo {
+            tail_len,
+            cycle_len,
+            hits,
+        }
+    }
+
+    /// Converts a start id's [`CycleInfo`] into the [`Progression`]s
+    /// [`Network::steps_parallel_general`] combines: an exact step for each
+    /// hit in the non-repeating tail, and a `step ≡ offset (mod lambda)`
+    /// congruence for each hit inside the detected cycle.
+    fn ghost_progressions(&self, start: NodeId, terminal: &HashSet<NodeId>) -> Vec<Progression> {
+        let info = self.analyze_from(start, terminal);
+
+        info.hits
+            .into_iter()
+            .map(|step| {
+                if step < info.tail_len {
+                    Progression::Exact(step)
+                } else {
+                    Progression::Periodic {
+                        offset: step,
+                        lambda: info.cycle_len,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// A general version of [`Network::steps_parallel`] that makes no
+    /// assumption about the shape of the input graph (unlike
+    /// `steps_parallel`, which only works because the puzzle input happens
+    /// to make a plain LCM correct).
+    ///
+    /// Each start node's hits on a `to_suffix` node are classified into a
+    /// tail-phase exact step or a cycle-phase congruence via
+    /// [`Network::ghost_progressions`]; the simultaneous answer is the
+    /// smallest step satisfying every ghost's progression at once, found by
+    /// combining them pairwise with the Chinese Remainder Theorem over the
+    /// cross-product of all ghosts' progressions. Returns `None` if no such
+    /// step exists.
+    ///
+    /// This already covers arbitrary graphs where a start node has more
+    /// than one `to_suffix` hit per cycle, or where tail and cycle hits are
+    /// interleaved -- there isn't a second, more-general solver to add on
+    /// top of this one.
+    pub fn steps_parallel_general(&self, from_suffix: &str, to_suffix: &str) -> Option<u64> {
+        let terminal = self.ids_ending_with(to_suffix);
+        let mut ghosts = self
+            .ids_ending_with(from_suffix)
+            .into_iter()
+            .map(|start| self.ghost_progressions(start, &terminal));
+
+        let mut combined = ghosts.next()?;
+        for progressions in ghosts {
+            combined = combined
+                .iter()
+                .flat_map(|&a| progressions.iter().filter_map(move |&b| a.combine(b)))
+                .collect();
+        }
+
+        combined.into_iter().map(Progression::min_step).min()
+    }
 }
 
 impl FromStr for Network {
@@ -102,12 +395,26 @@ impl FromStr for Network {
             .next()
             .ok_or_else(|| anyhow!("file must contain instructions"))?
             .into();
-        let map = lines
-            .filter_map(|s| Node::from_str(s).ok())
-            .map(|n| (n.name.clone(), n))
-            .collect::<HashMap<_, _>>();
 
-        Ok(Self { map, instructions })
+        // a node's id is a pure function of its name (a base-36 encoding),
+        // so there's no name -> id table to build up front, and no second
+        // pass needed to resolve forward references: a left/right name
+        // encodes to the same id whether or not that node has been parsed
+        // yet.
+        let mut adjacency = vec![None; 36usize.pow(NAME_LEN)];
+        let mut ids = Vec::new();
+
+        for node in lines.filter_map(|s| Node::from_str(s).ok()) {
+            let id = encode(&node.name);
+            adjacency[id as usize] = Some((encode(&node.left), encode(&node.right)));
+            ids.push(id);
+        }
+
+        Ok(Self {
+            adjacency,
+            ids,
+            instructions,
+        })
     }
 }
 
@@ -143,7 +450,7 @@ mod tests {
     fn parse_network() {
         let network = Network::from_str(EXAMPLE_MAP).unwrap();
         assert_eq!(network.instructions, "RL");
-        assert_eq!(network.map.len(), 7)
+        assert_eq!(network.ids.len(), 7)
     }
 
     #[test]
@@ -155,7 +462,7 @@ mod tests {
             ZZZ = (ZZZ, ZZZ)\n";
 
         let network = Network::from_str(s).unwrap();
-        assert_eq!(network.steps("AAA", |n| n.name == "ZZZ"), 6);
+        assert_eq!(network.steps("AAA", |name| name == "ZZZ"), 6);
     }
 
     #[test]
@@ -174,4 +481,112 @@ mod tests {
         let network = Network::from_str(s).unwrap();
         assert_eq!(network.steps_parallel("A", "Z"), 6);
     }
+
+    #[test]
+    fn network_steps_parallel_general_matches_lcm_case() {
+        let s = "\
+            LR\n\
+            \n\
+            11A = (11B, XXX)\n\
+            11B = (XXX, 11Z)\n\
+            11Z = (11B, XXX)\n\
+            22A = (22B, XXX)\n\
+            22B = (22C, 22C)\n\
+            22C = (22Z, 22Z)\n\
+            22Z = (22B, 22B)\n\
+            XXX = (XXX, XXX)\n";
+        let network = Network::from_str(s).unwrap();
+        assert_eq!(network.steps_parallel_general("A", "Z"), Some(6));
+    }
+
+    #[test]
+    fn network_steps_parallel_general_without_shared_cycle_length() {
+        // ghost 1 cycles with length 2, hitting `Z` every other step; ghost
+        // 2 cycles with length 6, hitting `Z` twice per cycle at offsets 3
+        // and 6. Only the offset-6 hit lines up with ghost 1's cycle, so
+        // the smallest simultaneous step (6) only falls out of combining
+        // every progression, not just the first hit of each ghost.
+        let s = "\
+            LR\n\
+            \n\
+            11A = (11B, 11B)\n\
+            11B = (11Z, 11Z)\n\
+            11Z = (11B, 11B)\n\
+            22A = (22B, 22B)\n\
+            22B = (22C, 22C)\n\
+            22C = (22Z, 22Z)\n\
+            22Z = (22B, 22B)\n";
+        let network = Network::from_str(s).unwrap();
+        assert_eq!(network.steps_parallel_general("A", "Z"), Some(6));
+    }
+
+    #[test]
+    fn network_steps_parallel_general_returns_none_when_never_simultaneous() {
+        // ghost 1 is only ever on a `Z` node at odd steps; ghost 2 is only
+        // ever on one at even steps, so the two can never coincide.
+        let s = "\
+            LR\n\
+            \n\
+            11A = (11Z, 11Z)\n\
+            11Z = (11A, 11A)\n\
+            22A = (22X, 22X)\n\
+            22X = (22Z, 22Z)\n\
+            22Z = (22X, 22X)\n";
+        let network = Network::from_str(s).unwrap();
+        assert_eq!(network.steps_parallel_general("A", "Z"), None);
+    }
+
+    #[test]
+    fn network_analyze() {
+        let s = "\
+            LR\n\
+            \n\
+            11A = (11B, 11B)\n\
+            11B = (11Z, 11Z)\n\
+            11Z = (11B, 11B)\n";
+        let network = Network::from_str(s).unwrap();
+
+        let info = network.analyze("11A", "Z");
+        assert_eq!(info.tail_len, 1);
+        assert_eq!(info.cycle_len, 2);
+        assert_eq!(info.hits, vec![2]);
+    }
+
+    #[test]
+    fn network_reaches() {
+        let s = "\
+            LR\n\
+            \n\
+            AAA = (BBB, CCC)\n\
+            BBB = (DDD, EEE)\n\
+            CCC = (ZZZ, GGG)\n\
+            DDD = (DDD, DDD)\n\
+            EEE = (EEE, EEE)\n\
+            GGG = (GGG, GGG)\n\
+            ZZZ = (ZZZ, ZZZ)\n";
+        let network = Network::from_str(s).unwrap();
+
+        let reaches_zzz = network.reaches("ZZZ");
+        assert_eq!(
+            reaches_zzz,
+            ["ZZZ", "CCC", "AAA"]
+                .map(String::from)
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn network_reaches_excludes_unrelated_sink() {
+        let s = "\
+            LR\n\
+            \n\
+            11A = (11B, XXX)\n\
+            11B = (XXX, 11Z)\n\
+            11Z = (11B, XXX)\n\
+            XXX = (XXX, XXX)\n";
+        let network = Network::from_str(s).unwrap();
+
+        assert!(!network.reaches("11Z").contains("XXX"));
+    }
 }
@@ -1,5 +1,7 @@
 use std::{collections::VecDeque, str::FromStr};
 
+use anyhow::{anyhow, bail};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     Future,
@@ -12,7 +14,57 @@ pub struct Reading {
 }
 
 impl Reading {
+    /// Computes the next (future) or previous (past) value via Newton's
+    /// forward difference formula, in `O(d)` extra space where `d` is the
+    /// number of difference rows, instead of [`Reading::analyze_pyramid`]'s
+    /// `O(d^2)` difference pyramid.
+    ///
+    /// Builds the leading-difference vector `d = [d_0, d_1, ..., d_m]`,
+    /// where `d_k` is the first element of the k-th difference row (stopping
+    /// once a row is all zeros), then sums `C(N, k) * d_k` for the future
+    /// value at index `N = nums.len()`, or `(-1)^k * d_k` for the past value
+    /// at index `-1` (since `C(-1, k) = (-1)^k`). The binomial coefficients
+    /// are accumulated iteratively (`C(N, k+1) = C(N, k) * (N-k) / (k+1)`)
+    /// rather than via factorials, to keep the intermediate values small.
     pub fn analyze(&self, mode: Mode) -> i64 {
+        let mut diffs = vec![];
+        let mut row: Vec<i64> = self.nums.iter().copied().collect();
+
+        loop {
+            diffs.push(row[0]);
+            if row.iter().all(|&x| x == 0) {
+                break;
+            }
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+
+        match mode {
+            Mode::Future => {
+                let n = self.nums.len() as i128;
+                let mut binom: i128 = 1;
+                let mut total: i128 = 0;
+
+                for (k, &d) in diffs.iter().enumerate() {
+                    total += binom * d as i128;
+                    binom = binom * (n - k as i128) / (k as i128 + 1);
+                }
+
+                total as i64
+            }
+            Mode::Past => diffs
+                .iter()
+                .enumerate()
+                .map(|(k, &d)| if k % 2 == 0 { d } else { -d })
+                .sum(),
+        }
+    }
+
+    /// The original difference-pyramid implementation of [`Reading::analyze`],
+    /// rebuilding the full pyramid into a `VecDeque<VecDeque<i64>>` and
+    /// walking it twice. Kept only as a reference to check the fast path
+    /// against in tests.
+    #[cfg(test)]
+    fn analyze_pyramid(&self, mode: Mode) -> i64 {
         let mut deque = VecDeque::new();
         deque.push_back(self.nums.clone());
         let mut frame = VecDeque::new();
@@ -52,13 +104,83 @@ impl Reading {
             Mode::Past => *front.front().unwrap(),
         }
     }
+
+    /// Returns the number of values in this reading.
+    pub fn len(&self) -> usize {
+        self.nums.len()
+    }
+
+    /// Returns whether this reading has no values.
+    pub fn is_empty(&self) -> bool {
+        self.nums.is_empty()
+    }
+
+    /// Computes the value at an arbitrary integer `offset` relative to this
+    /// sequence (`0` is the first element; positive offsets extend into the
+    /// future, negative offsets into the past), via Newton's forward
+    /// difference formula.
+    ///
+    /// Builds the forward-difference table as [`Reading::analyze`] does,
+    /// but keeps only the leading element of each row (`c[0]` is the
+    /// sequence's first value, `c[1]` the first difference row's first
+    /// value, and so on until the all-zero row). The value at index `p` is
+    /// then `sum_k C(p, k) * c[k]`, where `C(p, k)` is the falling-factorial
+    /// binomial coefficient evaluated for any integer `p`: a degree-`k`
+    /// polynomial in `p`, so it works directly for negative `p` too. This
+    /// lets callers jump straight to a far-off offset in `O(d)`, where `d`
+    /// is the number of difference rows, instead of stepping through every
+    /// value in between.
+    pub fn extrapolate(&self, offset: i64) -> i64 {
+        let mut coeffs = vec![];
+        let mut row: Vec<i64> = self.nums.iter().copied().collect();
+
+        loop {
+            coeffs.push(row[0]);
+            if row.iter().all(|&x| x == 0) {
+                break;
+            }
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+
+        coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| falling_binomial(offset, k) * c)
+            .sum()
+    }
+}
+
+/// Evaluates the falling-factorial binomial coefficient `C(p, k) =
+/// p*(p-1)*...*(p-k+1) / k!` for any integer `p`, not just `p >= k`.
+fn falling_binomial(p: i64, k: usize) -> i64 {
+    let numerator: i128 = (0..k as i64).map(|i| p as i128 - i as i128).product();
+    let denominator: i128 = (1..=k as i128).product::<i128>().max(1);
+
+    (numerator / denominator) as i64
 }
 
 impl FromStr for Reading {
     type Err = anyhow::Error;
 
+    /// Splits on arbitrary ASCII whitespace (so leading/trailing spaces,
+    /// tabs, and runs of multiple spaces don't produce spurious empty
+    /// tokens), tolerating `+`-signed integers via [`i64::from_str`].
+    ///
+    /// Rejects an empty (or all-whitespace) line outright, and reports which
+    /// specific token failed to parse, instead of letting an opaque
+    /// [`i64::from_str`] error propagate.
     fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
-        let nums = s.split(' ').map(i64::from_str).collect::<Result<_, _>>()?;
+        if s.trim().is_empty() {
+            bail!("OASIS report line is empty");
+        }
+
+        let nums = s
+            .split_whitespace()
+            .map(|tok| {
+                tok.parse::<i64>()
+                    .map_err(|_| anyhow!("invalid number `{tok}` in OASIS report line: `{s}`"))
+            })
+            .collect::<std::prelude::v1::Result<_, _>>()?;
 
         Ok(Self { nums })
     }
@@ -112,4 +234,76 @@ mod tests {
             assert_eq!(reading.analyze(Mode::Past), expected);
         }
     }
+
+    #[test]
+    fn reading_analyze_matches_pyramid_reference() {
+        for s in EXAMPLE_DATA.lines() {
+            let reading = Reading::from_str(s).unwrap();
+
+            for mode in [Mode::Future, Mode::Past] {
+                assert_eq!(reading.analyze(mode), reading.analyze_pyramid(mode));
+            }
+        }
+    }
+
+    #[test]
+    fn reading_extrapolate_matches_analyze_at_boundaries() {
+        for s in EXAMPLE_DATA.lines() {
+            let reading = Reading::from_str(s).unwrap();
+            let m = reading.nums.len() as i64;
+
+            assert_eq!(reading.extrapolate(m), reading.analyze(Mode::Future));
+            assert_eq!(reading.extrapolate(-1), reading.analyze(Mode::Past));
+        }
+    }
+
+    #[test]
+    fn reading_extrapolate_reproduces_known_values() {
+        // the sequence's own values are at indices 0..nums.len()
+        let reading = Reading::from_str("10 13 16 21 30 45").unwrap();
+        for (i, &n) in reading.nums.iter().enumerate() {
+            assert_eq!(reading.extrapolate(i as i64), n);
+        }
+    }
+
+    #[test]
+    fn parse_tolerates_irregular_whitespace() {
+        let reading = Reading::from_str("  0   3\t6  9 12 15 \n").unwrap();
+        assert_eq!(reading, Reading::from_str("0 3 6 9 12 15").unwrap());
+    }
+
+    #[test]
+    fn parse_tolerates_signed_integers() {
+        let reading = Reading::from_str("+1 -2 +3").unwrap();
+        assert_eq!(reading.nums, VecDeque::from([1, -2, 3]));
+    }
+
+    #[test]
+    fn parse_rejects_empty_line() {
+        assert!(Reading::from_str("").is_err());
+        assert!(Reading::from_str("   ").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_bad_token() {
+        let err = Reading::from_str("0 3 six 9").unwrap_err();
+        assert!(err.to_string().contains("six"));
+    }
+
+    #[test]
+    fn len_and_is_empty() {
+        let reading = Reading::from_str("0 3 6 9 12 15").unwrap();
+        assert_eq!(reading.len(), 6);
+        assert!(!reading.is_empty());
+    }
+
+    #[test]
+    fn reading_extrapolate_arbitrary_offset() {
+        // constant second differences (an arithmetic-of-arithmetic
+        // sequence), so the closed form must match manual extension in
+        // both directions without iterating one step at a time.
+        let reading = Reading::from_str("1 3 6 10 15").unwrap();
+        assert_eq!(reading.extrapolate(10), 66);
+        assert_eq!(reading.extrapolate(-5), 6);
+    }
 }
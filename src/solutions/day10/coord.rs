@@ -1,89 +1,153 @@
-#[derive(Debug)]
-pub enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+//! A generic, checked-arithmetic coordinate type.
+//!
+//! [`PosC<T>`] stores its two components in a `[T; 2]` array rather than
+//! separate named fields, so the same type works for both unsigned grids
+//! (`PosC<usize>`) and signed ones (`PosC<i64>`) without duplicating the
+//! `usize::MAX`/`0` boundary checks that hand-written `north`/`east`/
+//! `south`/`west` methods would otherwise need per coordinate type.
+
+use std::fmt;
+
+use crate::Direction;
 
-impl Direction {
-    pub fn with_coords(from: &Coord, to: &Coord) -> Option<Self> {
-        let dx = i64::try_from(to.x).ok()? - i64::try_from(from.x).ok()?;
-        let dy = i64::try_from(to.y).ok()? - i64::try_from(from.y).ok()?;
-
-        if dy > 0 {
-            return Some(Self::South);
-        }
-        if dy < 0 {
-            return Some(Self::North);
-        }
-        if dx > 0 {
-            return Some(Self::East);
-        }
-        if dx < 0 {
-            return Some(Self::West);
-        }
-
-        None
+/// Returned when a checked coordinate arithmetic operation overflows or
+/// underflows its component type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateOverflow;
+
+impl fmt::Display for CoordinateOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("coordinate arithmetic overflowed")
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
-pub struct Coord {
-    pub(crate) x: usize,
-    pub(crate) y: usize,
+impl std::error::Error for CoordinateOverflow {}
+
+/// Checked arithmetic for a [`PosC`] component type, so [`PosC::step`] can
+/// report overflow/underflow instead of silently wrapping.
+pub trait CheckedArith: Sized + Copy {
+    /// The unit value added/subtracted by [`PosC::step`].
+    fn one() -> Self;
+
+    fn checked_add(self, rhs: Self) -> Result<Self, CoordinateOverflow>;
+
+    fn checked_sub(self, rhs: Self) -> Result<Self, CoordinateOverflow>;
+
+    fn checked_neg(self) -> Result<Self, CoordinateOverflow>;
 }
 
-impl Coord {
-    /// Returns the coordinate directly north to this one.
-    ///
-    /// Returns `None` if the coordinate cannot be represented by [`usize`].
-    pub fn north(&self) -> Option<Coord> {
-        if self.y > 0 {
-            return Some(Coord {
-                x: self.x,
-                y: self.y - 1,
-            });
-        }
-        None
+macro_rules! impl_checked_arith {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl CheckedArith for $ty {
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(self, rhs: Self) -> Result<Self, CoordinateOverflow> {
+                    <$ty>::checked_add(self, rhs).ok_or(CoordinateOverflow)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Result<Self, CoordinateOverflow> {
+                    <$ty>::checked_sub(self, rhs).ok_or(CoordinateOverflow)
+                }
+
+                fn checked_neg(self) -> Result<Self, CoordinateOverflow> {
+                    <$ty>::checked_neg(self).ok_or(CoordinateOverflow)
+                }
+            }
+        )+
+    };
+}
+
+impl_checked_arith!(usize, isize, i32, i64);
+
+/// A 2D coordinate generic over its component type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosC<T> {
+    coords: [T; 2],
+}
+
+impl<T: CheckedArith> PosC<T> {
+    /// Creates a new [`PosC`] from the given `(x, y)`.
+    pub fn new(x: T, y: T) -> Self {
+        Self { coords: [x, y] }
     }
 
-    /// Returns the coordinate directly east to this one.
-    ///
-    /// Returns `None` if the coordinate cannot be represented by [`usize`].
-    pub fn east(&self) -> Option<Coord> {
-        if self.x < usize::MAX {
-            return Some(Coord {
-                x: self.x + 1,
-                y: self.y,
-            });
-        }
-        None
+    /// The first (horizontal) component.
+    pub fn x(&self) -> T {
+        self.coords[0]
     }
 
-    /// Returns the coordinate directly south to this one.
-    ///
-    /// Returns `None` if the coordinate cannot be represented by [`usize`].
-    pub fn south(&self) -> Option<Coord> {
-        if self.y < usize::MAX {
-            return Some(Coord {
-                x: self.x,
-                y: self.y + 1,
-            });
-        }
-        None
+    /// The second (vertical) component.
+    pub fn y(&self) -> T {
+        self.coords[1]
     }
 
-    /// Returns the coordinate directly west to this one.
+    /// Returns the coordinate one unit over in the given `dir`, via checked
+    /// arithmetic on the affected component.
     ///
-    /// Returns `None` if the coordinate cannot be represented by [`usize`].
-    pub fn west(&self) -> Option<Coord> {
-        if self.x > 0 {
-            return Some(Coord {
-                x: self.x - 1,
-                y: self.y,
-            });
-        }
-        None
+    /// Returns `None` on overflow or underflow, rather than wrapping or
+    /// panicking.
+    pub fn step(&self, dir: Direction) -> Option<Self> {
+        let one = T::one();
+
+        let (x, y) = match dir {
+            Direction::North => (self.x(), self.y().checked_sub(one).ok()?),
+            Direction::South => (self.x(), self.y().checked_add(one).ok()?),
+            Direction::East => (self.x().checked_add(one).ok()?, self.y()),
+            Direction::West => (self.x().checked_sub(one).ok()?, self.y()),
+        };
+
+        Some(Self::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_moves_one_unit() {
+        let p = PosC::new(1usize, 1usize);
+
+        assert_eq!(p.step(Direction::North), Some(PosC::new(1, 0)));
+        assert_eq!(p.step(Direction::South), Some(PosC::new(1, 2)));
+        assert_eq!(p.step(Direction::East), Some(PosC::new(2, 1)));
+        assert_eq!(p.step(Direction::West), Some(PosC::new(0, 1)));
+    }
+
+    #[test]
+    fn step_underflows_at_origin() {
+        let origin = PosC::new(0usize, 0usize);
+
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(origin.step(Direction::West), None);
+    }
+
+    #[test]
+    fn step_overflows_at_max() {
+        let edge = PosC::new(usize::MAX, usize::MAX);
+
+        assert_eq!(edge.step(Direction::East), None);
+        assert_eq!(edge.step(Direction::South), None);
+    }
+
+    #[test]
+    fn step_works_for_signed_components() {
+        let p = PosC::new(-1i64, -1i64);
+        assert_eq!(p.step(Direction::North), Some(PosC::new(-1, -2)));
+    }
+
+    #[test]
+    fn checked_arith_reports_overflow() {
+        assert_eq!(
+            CheckedArith::checked_add(usize::MAX, 1),
+            Err(CoordinateOverflow)
+        );
+        assert_eq!(
+            CheckedArith::checked_sub(0usize, 1),
+            Err(CoordinateOverflow)
+        );
     }
 }
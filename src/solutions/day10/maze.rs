@@ -3,10 +3,104 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 
 use crate::{map, Coordinate, Direction, Grid};
 
+/// A single maze tile: a pipe shape, ground, or the not-yet-resolved start
+/// tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    /// `|`, connecting north and south.
+    NorthSouth,
+    /// `-`, connecting east and west.
+    EastWest,
+    /// `L`, connecting north and east.
+    NorthEast,
+    /// `J`, connecting north and west.
+    NorthWest,
+    /// `7`, connecting south and west.
+    SouthWest,
+    /// `F`, connecting south and east.
+    SouthEast,
+    /// `.`, no connections.
+    Ground,
+    /// `S`, the animal's starting tile. Connects in every direction until
+    /// [`Maze::replace_start_tile`] resolves its true shape.
+    Start,
+}
+
+impl Tile {
+    /// Returns the directions this tile connects to.
+    ///
+    /// This is the single source of truth for pipe-connection rules: it
+    /// replaces what used to be separate, independently-maintained `match`
+    /// statements for adjacency checks, quadrant subsampling, and start-tile
+    /// inference.
+    fn connections(&self) -> &'static [Direction] {
+        use Direction::*;
+
+        match self {
+            Tile::NorthSouth => &[North, South],
+            Tile::EastWest => &[East, West],
+            Tile::NorthEast => &[North, East],
+            Tile::NorthWest => &[North, West],
+            Tile::SouthWest => &[South, West],
+            Tile::SouthEast => &[South, East],
+            Tile::Ground => &[],
+            Tile::Start => &[North, East, South, West],
+        }
+    }
+
+    /// Returns this tile's character representation.
+    fn as_char(&self) -> char {
+        match self {
+            Tile::NorthSouth => '|',
+            Tile::EastWest => '-',
+            Tile::NorthEast => 'L',
+            Tile::NorthWest => 'J',
+            Tile::SouthWest => '7',
+            Tile::SouthEast => 'F',
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '|' => Ok(Tile::NorthSouth),
+            '-' => Ok(Tile::EastWest),
+            'L' => Ok(Tile::NorthEast),
+            'J' => Ok(Tile::NorthWest),
+            '7' => Ok(Tile::SouthWest),
+            'F' => Ok(Tile::SouthEast),
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::Start),
+            _ => bail!("unknown tile character: {c:?}"),
+        }
+    }
+}
+
+/// The six pipe-shaped tiles, excluding [`Tile::Ground`] and [`Tile::Start`].
+const PIPE_TILES: [Tile; 6] = [
+    Tile::NorthSouth,
+    Tile::EastWest,
+    Tile::NorthEast,
+    Tile::NorthWest,
+    Tile::SouthWest,
+    Tile::SouthEast,
+];
+
 #[derive(Debug, Default, PartialEq, Eq)]
 struct Sample {
     top_left: bool,
@@ -26,51 +120,49 @@ impl Sample {
         }
     }
 
-    /// Creates a [`Sample`] from a pipe character and starting top-left fill
-    /// value.
+    /// Creates a [`Sample`] from a tile and starting top-left fill value.
     ///
     /// The value inferred by the top-left assumes a left-to-right scan order
     /// for subsamples.
-    fn with_pipe(pipe: char, top_left: bool) -> Self {
+    fn with_pipe(tile: Tile, top_left: bool) -> Self {
         let mut sample = Self::new(top_left);
 
-        match pipe {
-            '|' => {
+        match tile {
+            Tile::NorthSouth => {
                 sample.top_right = !top_left;
                 sample.bot_left = top_left;
                 sample.bot_right = !top_left;
             }
-            '-' => {
+            Tile::EastWest => {
                 sample.top_right = top_left;
                 sample.bot_left = !top_left;
                 sample.bot_right = !top_left;
             }
-            'L' => {
+            Tile::NorthEast => {
                 sample.top_right = !top_left;
                 sample.bot_left = top_left;
                 sample.bot_right = top_left;
             }
-            'J' => {
+            Tile::NorthWest => {
                 sample.top_right = !top_left;
                 sample.bot_left = !top_left;
                 sample.bot_right = !top_left;
             }
-            '7' => {
+            Tile::SouthWest => {
                 sample.top_right = top_left;
                 sample.bot_left = !top_left;
                 sample.bot_right = top_left;
             }
-            'F' => {
+            Tile::SouthEast => {
                 sample.top_right = top_left;
                 sample.bot_left = top_left;
                 sample.bot_right = !top_left;
             }
-            '.' => {
+            Tile::Ground | Tile::Start => {
                 sample.top_right = top_left;
                 sample.bot_left = top_left;
                 sample.bot_right = top_left;
             }
-            _ => panic!("unknown tile"),
         }
 
         sample
@@ -82,15 +174,55 @@ impl Sample {
     }
 }
 
+/// A tile's classification relative to the main loop, as produced by
+/// [`Maze::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// Reachable from the edge of the grid without crossing the main loop,
+    /// including by "squeezing" between adjacent pipes.
+    Outside,
+    /// Fully enclosed by the main loop.
+    Inside,
+    /// Part of the main loop itself.
+    Pipe,
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Zone::Outside => 'O',
+            Zone::Inside => 'I',
+            Zone::Pipe => 'P',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// Output style for [`Maze::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Show only main-loop pipes, with `S` restored to its inferred shape;
+    /// every other tile is rendered as ground (`.`).
+    Loop,
+    /// Mark enclosed tiles with `I` and exterior ground with `O`, matching
+    /// the annotated examples in the puzzle text. Main-loop tiles are left
+    /// as-is.
+    InsideOutside,
+    /// Replace each main-loop tile with its BFS distance from `start`,
+    /// modulo 10 so every tile still renders as a single character; every
+    /// other tile is rendered as ground (`.`).
+    Distances,
+}
+
 #[derive(Debug)]
 pub struct Maze {
-    grid: Grid<char>,
+    grid: Grid<Tile>,
     start: Coordinate,
     main_loop: HashSet<Coordinate>,
 }
 
 impl Maze {
-    fn new(grid: Grid<char>, start: Coordinate) -> Self {
+    fn new(grid: Grid<Tile>, start: Coordinate) -> Self {
         let mut maze = Self {
             grid,
             start,
@@ -113,29 +245,20 @@ impl Maze {
     /// Checks whether or not `pos` can connect to the tile in the given
     /// direction.
     ///
-    /// This method validates the boundaries of the grid and rules of pipe
-    /// connections.
+    /// This method validates the boundaries of the grid; the connection
+    /// rule itself is just [`Tile::connections`].
     fn connects_to_dir(&self, pos: Coordinate, dir: Direction) -> bool {
         if !self.grid.contains(pos) {
             return false;
         }
-        let c = self.grid[pos];
-
-        if c == 'S' {
-            return true;
-        }
 
-        match dir {
-            Direction::North => c == '|' || c == 'L' || c == 'J',
-            Direction::East => c == '-' || c == 'L' || c == 'F',
-            Direction::South => c == '|' || c == '7' || c == 'F',
-            Direction::West => c == '-' || c == 'J' || c == '7',
-        }
+        self.grid[pos].connections().contains(&dir)
     }
 
     /// Checks if `from` can connect to the `to` coordinate.
     fn connects(&self, from: Coordinate, to: Coordinate) -> bool {
         from.direction(to)
+            .and_then(|h| h.as_cardinal())
             .map(|d| self.connects_to_dir(from, d))
             .unwrap_or_default()
     }
@@ -146,7 +269,46 @@ impl Maze {
         self.connects(a, b) && self.connects(b, a)
     }
 
+    /// Walks the main loop starting from `start`, returning its vertices in
+    /// traversal order.
+    ///
+    /// At each step, the single connected neighbor that isn't the tile we
+    /// just came from is chosen via [`Maze::is_connected`], until the walk
+    /// returns to `start`. Unlike the `HashSet` built by
+    /// [`Maze::trace_main_loop`], this preserves the cyclic ordering needed
+    /// by area/geometry algorithms (e.g. the shoelace formula) and
+    /// visualization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` doesn't have exactly two connected neighbors, which
+    /// should not happen for a valid main loop.
+    pub fn walk_loop(&self) -> Vec<Coordinate> {
+        let mut path = vec![self.start];
+
+        let mut prev = self.start;
+        let mut current = self.adjacent(self.start)[0];
+        while current != self.start {
+            path.push(current);
+
+            let next = self
+                .adjacent(current)
+                .into_iter()
+                .find(|&n| n != prev)
+                .expect("loop tile must have exactly two connected neighbors");
+            prev = current;
+            current = next;
+        }
+
+        path
+    }
+
     pub fn furthest(&self) -> u64 {
+        self.distances().values().copied().max().unwrap_or_default()
+    }
+
+    /// Returns each main-loop tile's BFS distance (in steps) from `start`.
+    fn distances(&self) -> HashMap<Coordinate, u64> {
         let mut distances: HashMap<Coordinate, u64> = map![];
         let mut nodes = VecDeque::from([(self.start, 0u64)]);
 
@@ -161,7 +323,7 @@ impl Maze {
             }
         }
 
-        distances.values().copied().max().unwrap_or_default()
+        distances
     }
 
     /// Returns the number of tiles fully enclosed by the main loop.
@@ -205,6 +367,253 @@ impl Maze {
         total
     }
 
+    /// Returns the number of tiles enclosed by the main loop, computed via
+    /// the shoelace formula and [Pick's theorem][pick], as a cross-check
+    /// against (and faster alternative to) [`Maze::enclosed`]'s subsampling
+    /// approach.
+    ///
+    /// The shoelace formula gives the polygon area `A` traced by
+    /// [`Maze::walk_loop`]'s ordered vertices; Pick's theorem then relates
+    /// that area to the interior point count `I` via `A = I + b/2 - 1`,
+    /// where `b` is the number of boundary points (the loop length). Solving
+    /// for `I` gives exactly the number of enclosed tiles.
+    ///
+    /// This runs in `O(loop length)`, rather than `O(grid area)`.
+    ///
+    /// [pick]: https://en.wikipedia.org/wiki/Pick%27s_theorem
+    pub fn enclosed_pick(&self) -> u64 {
+        let path = self.walk_loop();
+        let b = path.len() as i128;
+
+        let mut double_area: i128 = 0;
+        for i in 0..path.len() {
+            let (x1, y1) = (path[i].x as i128, path[i].y as i128);
+            let (x2, y2) = {
+                let next = path[(i + 1) % path.len()];
+                (next.x as i128, next.y as i128)
+            };
+            double_area += x1 * y2 - x2 * y1;
+        }
+
+        let double_area = double_area.unsigned_abs();
+        let interior = (double_area - b.unsigned_abs() + 2) / 2;
+
+        interior as u64
+    }
+
+    /// Returns the number of tiles enclosed by the main loop, computed with
+    /// a flood fill over a 3x-expanded grid, as a second independent
+    /// cross-check against [`Maze::enclosed`] and [`Maze::enclosed_pick`].
+    ///
+    /// Each original tile maps to a 3x3 cell: loop tiles fill their center
+    /// plus an arm per connected direction (e.g. `F` fills center,
+    /// east-middle, and south-middle), so that pipes which only share a
+    /// corner in the original grid still block a 4-connected flood fill
+    /// between them. Non-loop tiles stay empty. A flood fill from every
+    /// border cell of the expanded grid then reaches everything outside the
+    /// loop, including tiles only reachable by "squeezing" between pipes;
+    /// any original tile whose expanded center wasn't reached is enclosed.
+    pub fn enclosed_floodfill(&self) -> u64 {
+        self.enclosed_mask()
+            .iter()
+            .flatten()
+            .filter(|&&enclosed| enclosed)
+            .count() as u64
+    }
+
+    /// Builds the `height x width` enclosed/exterior classification used by
+    /// [`Maze::enclosed_floodfill`] and [`RenderMode::InsideOutside`], via a
+    /// flood fill over a 3x-expanded grid.
+    ///
+    /// Each original tile maps to a 3x3 cell: loop tiles fill their center
+    /// plus an arm per connected direction (e.g. `F` fills center,
+    /// east-middle, and south-middle), so that pipes which only share a
+    /// corner in the original grid still block a 4-connected flood fill
+    /// between them. Non-loop tiles stay empty. A flood fill from every
+    /// border cell of the expanded grid then reaches everything outside the
+    /// loop, including tiles only reachable by "squeezing" between pipes;
+    /// any original tile whose expanded center wasn't reached is enclosed.
+    fn enclosed_mask(&self) -> Vec<Vec<bool>> {
+        let width = self.grid.width();
+        let height = self.grid.height();
+        let (ew, eh) = (width * 3, height * 3);
+
+        let mut blocked = vec![false; ew * eh];
+        for &pos in &self.main_loop {
+            let (cx, cy) = (pos.x * 3 + 1, pos.y * 3 + 1);
+            blocked[cy * ew + cx] = true;
+
+            if self.connects_to_dir(pos, Direction::North) {
+                blocked[(cy - 1) * ew + cx] = true;
+            }
+            if self.connects_to_dir(pos, Direction::East) {
+                blocked[cy * ew + (cx + 1)] = true;
+            }
+            if self.connects_to_dir(pos, Direction::South) {
+                blocked[(cy + 1) * ew + cx] = true;
+            }
+            if self.connects_to_dir(pos, Direction::West) {
+                blocked[cy * ew + (cx - 1)] = true;
+            }
+        }
+
+        let mut outside = vec![false; ew * eh];
+        let mut queue = VecDeque::new();
+        let mut enqueue = |x: usize, y: usize, queue: &mut VecDeque<(usize, usize)>| {
+            if !blocked[y * ew + x] && !outside[y * ew + x] {
+                outside[y * ew + x] = true;
+                queue.push_back((x, y));
+            }
+        };
+        for x in 0..ew {
+            enqueue(x, 0, &mut queue);
+            enqueue(x, eh - 1, &mut queue);
+        }
+        for y in 0..eh {
+            enqueue(0, y, &mut queue);
+            enqueue(ew - 1, y, &mut queue);
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            if y > 0 {
+                enqueue(x, y - 1, &mut queue);
+            }
+            if y + 1 < eh {
+                enqueue(x, y + 1, &mut queue);
+            }
+            if x > 0 {
+                enqueue(x - 1, y, &mut queue);
+            }
+            if x + 1 < ew {
+                enqueue(x + 1, y, &mut queue);
+            }
+        }
+
+        let mut mask = vec![vec![false; width]; height];
+        for (y, row) in mask.iter_mut().enumerate() {
+            for (x, enclosed) in row.iter_mut().enumerate() {
+                if self.main_loop.contains(&Coordinate::new(x, y)) {
+                    continue;
+                }
+                let (cx, cy) = (x * 3 + 1, y * 3 + 1);
+                *enclosed = !outside[cy * ew + cx];
+            }
+        }
+
+        mask
+    }
+
+    /// Classifies every tile as [`Zone::Pipe`] (on the main loop),
+    /// [`Zone::Inside`] (enclosed), or [`Zone::Outside`] (reachable from the
+    /// grid's border), via the same 3x-expanded flood fill used by
+    /// [`Maze::enclosed_floodfill`].
+    ///
+    /// This gives a renderable per-tile classification, and a second
+    /// independent cross-check of the interior count (`enclosed()` is just
+    /// the number of [`Zone::Inside`] tiles), rather than only a scalar
+    /// total.
+    pub fn classify(&self) -> Grid<Zone> {
+        let mask = self.enclosed_mask();
+        let width = self.grid.width();
+
+        let mut zones = Vec::with_capacity(width * self.grid.height());
+        for (y, row) in mask.into_iter().enumerate() {
+            for (x, enclosed) in row.into_iter().enumerate() {
+                zones.push(if self.main_loop.contains(&Coordinate::new(x, y)) {
+                    Zone::Pipe
+                } else if enclosed {
+                    Zone::Inside
+                } else {
+                    Zone::Outside
+                });
+            }
+        }
+
+        Grid::new(zones.into_iter(), width)
+    }
+
+    /// Builds a [`petgraph::Graph`] over the main loop's coordinates, with an
+    /// edge between every pair of pipe-connected tiles, plus a lookup from
+    /// each [`Coordinate`] to its node index.
+    ///
+    /// This hands the main loop over to the broader petgraph toolbox
+    /// (connected components, alternative shortest-path algorithms, DOT
+    /// export for visualization) without reimplementing it here; [`Maze`]'s
+    /// own `furthest`/`enclosed*` methods remain the default fast paths.
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(
+        &self,
+    ) -> (
+        petgraph::Graph<Coordinate, ()>,
+        HashMap<Coordinate, petgraph::graph::NodeIndex>,
+    ) {
+        let mut graph = petgraph::Graph::new();
+        let indices: HashMap<Coordinate, petgraph::graph::NodeIndex> = self
+            .main_loop
+            .iter()
+            .map(|&pos| (pos, graph.add_node(pos)))
+            .collect();
+
+        for &pos in &self.main_loop {
+            for next in self.adjacent(pos) {
+                graph.add_edge(indices[&pos], indices[&next], ());
+            }
+        }
+
+        (graph, indices)
+    }
+
+    /// Renders the maze as ASCII art according to `mode`.
+    ///
+    /// This is primarily a debugging and visualization aid: printing the
+    /// classified grid (which tiles are on the loop, which are inside or
+    /// outside it, how far along the loop each tile is) is what makes
+    /// flood-fill and parity bugs in [`Maze::enclosed`] and its relatives
+    /// obvious at a glance, rather than only being visible as a wrong final
+    /// count.
+    pub fn render(&self, mode: RenderMode) -> String {
+        let mask = matches!(mode, RenderMode::InsideOutside).then(|| self.enclosed_mask());
+        let distances = matches!(mode, RenderMode::Distances).then(|| self.distances());
+
+        let mut out = String::new();
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+
+            for (x, &tile) in row.iter().enumerate() {
+                let pos = Coordinate::new(x, y);
+                let on_loop = self.main_loop.contains(&pos);
+
+                let rendered = match mode {
+                    RenderMode::Loop => {
+                        if on_loop {
+                            tile.as_char()
+                        } else {
+                            '.'
+                        }
+                    }
+                    RenderMode::InsideOutside => {
+                        if on_loop {
+                            tile.as_char()
+                        } else if mask.as_ref().unwrap()[y][x] {
+                            'I'
+                        } else {
+                            'O'
+                        }
+                    }
+                    RenderMode::Distances => match distances.as_ref().unwrap().get(&pos) {
+                        Some(&d) => char::from_digit((d % 10) as u32, 10).unwrap(),
+                        None => '.',
+                    },
+                };
+                out.push(rendered);
+            }
+        }
+
+        out
+    }
+
     /// Traces the main loop following pipe connection rules, and stores those
     /// coordinates internally.
     fn trace_main_loop(&mut self) {
@@ -222,42 +631,33 @@ impl Maze {
         self.main_loop = visited;
     }
 
+    /// Infers `start`'s true pipe shape from which of its neighbors connect
+    /// back to it, and replaces the `Start` tile in the grid with it.
     fn replace_start_tile(&mut self) {
-        let dirs = [
-            self.start.north(),
-            self.start.east(),
-            self.start.south(),
-            self.start.west(),
+        let open: HashSet<Direction> = [
+            (Direction::North, self.start.north()),
+            (Direction::East, self.start.east()),
+            (Direction::South, self.start.south()),
+            (Direction::West, self.start.west()),
         ]
         .into_iter()
-        .map(|opt| {
-            opt.map(|c| self.connects(c, self.start))
-                .unwrap_or_default()
-        })
-        .collect::<Vec<_>>();
-
-        let (n, e, s, w) = (dirs[0], dirs[1], dirs[2], dirs[3]);
-        let c = match (n, e, s, w) {
-            (true, false, true, false) => '|',
-            (false, true, false, true) => '-',
-            (true, true, false, false) => 'L',
-            (true, false, false, true) => 'J',
-            (false, false, true, true) => '7',
-            (false, true, true, false) => 'F',
-            _ => panic!("unknown starting tile from connections"),
-        };
-        self.grid[self.start] = c;
+        .filter_map(|(dir, pos)| pos.filter(|&c| self.connects(c, self.start)).map(|_| dir))
+        .collect();
+
+        let tile = PIPE_TILES
+            .into_iter()
+            .find(|t| t.connections().iter().copied().collect::<HashSet<_>>() == open)
+            .expect("unknown starting tile from connections");
+
+        self.grid[self.start] = tile;
     }
 
-    /// Searches the grid for an `S` tile and returns the coordinate if found.
-    fn find_start_pos(grid: &Grid<char>) -> Option<Coordinate> {
+    /// Searches the grid for a [`Tile::Start`] tile and returns its
+    /// coordinate if found.
+    fn find_start_pos(grid: &Grid<Tile>) -> Option<Coordinate> {
         for (y, line) in grid.rows().enumerate() {
-            if !line.contains(&'S') {
-                continue;
-            }
-
-            for (x, &c) in line.iter().enumerate() {
-                if c == 'S' {
+            for (x, &tile) in line.iter().enumerate() {
+                if tile == Tile::Start {
                     return Some(Coordinate { x, y });
                 }
             }
@@ -271,18 +671,32 @@ impl FromStr for Maze {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
-        let grid = Grid::from_str(s)?;
-
-        // // verify grid dimensions
-        // if grid.is_empty() || grid[0].is_empty() {
-        //     bail!("grid must not be empty");
-        // }
-        // let width = grid[0].len();
-        // if !grid.iter().all(|row| row.len() == width) {
-        //     bail!("grid must have equal width for all columns");
-        // }
-
-        // find start position
+        let mut tiles = vec![];
+        let mut width = None;
+
+        for line in s.lines() {
+            let mut w = 0;
+            for c in line.chars() {
+                tiles.push(Tile::try_from(c)?);
+                w += 1;
+            }
+
+            if let Some(width) = width {
+                if width != w {
+                    bail!(
+                        "grid data contains inconsistent row \
+                        sizes (expected {width}, got {w})"
+                    );
+                }
+            }
+            width = Some(w);
+        }
+
+        let width = width
+            .filter(|&w| w > 0)
+            .ok_or_else(|| anyhow!("grid must not be empty"))?;
+        let grid = Grid::new(tiles.into_iter(), width);
+
         let start = Self::find_start_pos(&grid)
             .ok_or_else(|| anyhow!("grid must contain a start position"))?;
 
@@ -378,6 +792,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn maze_walk_loop() {
+        let maze = Maze::from_str(SIMPLE_PIPES).unwrap();
+        let path = maze.walk_loop();
+
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], maze.start);
+        for pair in path.windows(2) {
+            assert!(maze.is_connected(pair[0], pair[1]));
+        }
+        assert!(maze.is_connected(path[path.len() - 1], path[0]));
+    }
+
+    #[test]
+    fn maze_walk_loop_matches_furthest() {
+        let data = "\
+            7-F7-\n\
+            .FJ|7\n\
+            SJLL7\n\
+            |F--J\n\
+            LJ.LJ\n";
+        let maze = Maze::from_str(data).unwrap();
+        assert_eq!(maze.walk_loop().len() as u64 / 2, maze.furthest());
+    }
+
     #[test]
     fn maze_furthest() {
         let data = "\
@@ -406,4 +845,150 @@ mod tests {
         let maze = Maze::from_str(data).unwrap();
         assert_eq!(maze.enclosed(), 10);
     }
+
+    #[test]
+    fn maze_enclosed_pick_matches_enclosed() {
+        let examples = [
+            SIMPLE_PIPES,
+            "\
+            FF7FSF7F7F7F7F7F---7\n\
+            L|LJ||||||||||||F--J\n\
+            FL-7LJLJ||||||LJL-77\n\
+            F--JF--7||LJLJ7F7FJ-\n\
+            L---JF-JLJ.||-FJLJJ7\n\
+            |F|F-JF---7F7-L7L|7|\n\
+            |FFJF7L7F-JF7|JL---7\n\
+            7-L-JL7||F7|L7F-7F7|\n\
+            L.L7LFJ|||||FJL7||LJ\n\
+            L7JLJL-JLJLJL--JLJ.L\n",
+        ];
+
+        for data in examples {
+            let maze = Maze::from_str(data).unwrap();
+            assert_eq!(maze.enclosed_pick(), maze.enclosed());
+        }
+    }
+
+    #[test]
+    fn maze_enclosed_floodfill_matches_enclosed() {
+        let examples = [
+            SIMPLE_PIPES,
+            "\
+            FF7FSF7F7F7F7F7F---7\n\
+            L|LJ||||||||||||F--J\n\
+            FL-7LJLJ||||||LJL-77\n\
+            F--JF--7||LJLJ7F7FJ-\n\
+            L---JF-JLJ.||-FJLJJ7\n\
+            |F|F-JF---7F7-L7L|7|\n\
+            |FFJF7L7F-JF7|JL---7\n\
+            7-L-JL7||F7|L7F-7F7|\n\
+            L.L7LFJ|||||FJL7||LJ\n\
+            L7JLJL-JLJLJL--JLJ.L\n",
+        ];
+
+        for data in examples {
+            let maze = Maze::from_str(data).unwrap();
+            assert_eq!(maze.enclosed_floodfill(), maze.enclosed());
+        }
+    }
+
+    #[test]
+    fn maze_classify_matches_enclosed_floodfill() {
+        let examples = [
+            SIMPLE_PIPES,
+            "\
+            FF7FSF7F7F7F7F7F---7\n\
+            L|LJ||||||||||||F--J\n\
+            FL-7LJLJ||||||LJL-77\n\
+            F--JF--7||LJLJ7F7FJ-\n\
+            L---JF-JLJ.||-FJLJJ7\n\
+            |F|F-JF---7F7-L7L|7|\n\
+            |FFJF7L7F-JF7|JL---7\n\
+            7-L-JL7||F7|L7F-7F7|\n\
+            L.L7LFJ|||||FJL7||LJ\n\
+            L7JLJL-JLJLJL--JLJ.L\n",
+        ];
+
+        for data in examples {
+            let maze = Maze::from_str(data).unwrap();
+            let inside = maze
+                .classify()
+                .rows()
+                .flatten()
+                .filter(|&&z| z == Zone::Inside)
+                .count() as u64;
+            assert_eq!(inside, maze.enclosed_floodfill());
+        }
+    }
+
+    #[test]
+    fn maze_classify_marks_loop_tiles_as_pipe() {
+        let maze = Maze::from_str(SIMPLE_PIPES).unwrap();
+        let zones = maze.classify();
+
+        for &pos in &maze.main_loop {
+            assert_eq!(zones[pos], Zone::Pipe);
+        }
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn maze_to_petgraph_covers_main_loop() {
+        let maze = Maze::from_str(SIMPLE_PIPES).unwrap();
+        let (graph, indices) = maze.to_petgraph();
+
+        assert_eq!(graph.node_count(), maze.main_loop.len());
+        assert_eq!(indices.len(), maze.main_loop.len());
+        for &pos in &maze.main_loop {
+            assert_eq!(graph[indices[&pos]], pos);
+        }
+        // each loop tile has exactly two neighbors, contributing two directed
+        // edges per tile
+        assert_eq!(graph.edge_count(), maze.main_loop.len() * 2);
+    }
+
+    #[test]
+    fn maze_render_loop() {
+        let maze = Maze::from_str(EXAMPLE_PIPES).unwrap();
+        let expected = "\
+            .....\n\
+            .F-7.\n\
+            .|.|.\n\
+            .L-J.\n\
+            .....";
+        assert_eq!(maze.render(RenderMode::Loop), expected);
+    }
+
+    #[test]
+    fn maze_render_distances() {
+        let maze = Maze::from_str(SIMPLE_PIPES).unwrap();
+        let expected = ".....\n.012.\n.1.3.\n.234.\n.....";
+        assert_eq!(maze.render(RenderMode::Distances), expected);
+    }
+
+    #[test]
+    fn maze_render_inside_outside() {
+        let data = "\
+            ...........\n\
+            .S-------7.\n\
+            .|F-----7|.\n\
+            .||.....||.\n\
+            .||.....||.\n\
+            .|L-7.F-J|.\n\
+            .|..|.|..|.\n\
+            .L--J.L--J.\n\
+            ...........\n";
+        let maze = Maze::from_str(data).unwrap();
+        let expected = "\
+            OOOOOOOOOOO\n\
+            OF-------7O\n\
+            O|F-----7|O\n\
+            O||OOOOO||O\n\
+            O||OOOOO||O\n\
+            O|L-7OF-J|O\n\
+            O|II|O|II|O\n\
+            OL--JOL--JO\n\
+            OOOOOOOOOOO";
+        assert_eq!(maze.render(RenderMode::InsideOutside), expected);
+    }
 }
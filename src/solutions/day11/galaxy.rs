@@ -1,4 +1,7 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use anyhow::bail;
 
@@ -9,11 +12,13 @@ pub struct GalaxyMap {
     expansion_factor: usize,
     expanded_rows: Vec<usize>,
     expanded_cols: Vec<usize>,
+    remapped: HashMap<Coordinate, Coordinate>,
 }
 
 impl GalaxyMap {
     pub fn set_expansion(&mut self, factor: usize) {
         self.expansion_factor = factor;
+        self.rebuild_remapped();
     }
 
     pub fn galaxy_coords(&self) -> &[Coordinate] {
@@ -31,54 +36,42 @@ impl GalaxyMap {
         values
     }
 
+    /// Returns the Manhattan distance between two galaxies, accounting for
+    /// expansion of any empty rows/columns between them.
+    ///
+    /// Both coordinates must be galaxies this map was built from, since the
+    /// distance is computed from precomputed, expansion-adjusted coordinates
+    /// rather than scanning for crossings on every call.
     pub fn distance(&self, from: &Coordinate, to: &Coordinate) -> usize {
-        let lo_x = from.x.min(to.x);
-        let hi_x = from.x.max(to.x);
-        let lo_y = from.y.min(to.y);
-        let hi_y = from.y.max(to.y);
-        let distance = (hi_y - lo_y) + (hi_x - lo_x);
-
-        if self.expansion_factor == 0 {
-            return distance;
-        }
-
-        let count = self.expanded_crossings(from, to);
-        let factor = if self.expansion_factor == 1 {
-            1
-        } else {
-            self.expansion_factor - 1
-        };
+        let from = self.remapped[from];
+        let to = self.remapped[to];
 
-        distance + (count * factor)
+        from.x.abs_diff(to.x) + from.y.abs_diff(to.y)
     }
 
-    fn expanded_crossings(&self, from: &Coordinate, to: &Coordinate) -> usize {
-        let mut count = 0;
+    /// Recomputes each galaxy's expansion-adjusted coordinate, shifting it by
+    /// `(expanded rows/cols before it) * extra` along each axis, where
+    /// `extra` is `expansion_factor - 1` for a factor of 2 or more, `1` for a
+    /// factor of 1 (each empty row/column counts twice), and `0` for a
+    /// factor of 0, so that [`GalaxyMap::distance`] reduces to a plain
+    /// Manhattan distance with no per-pair scanning.
+    fn rebuild_remapped(&mut self) {
+        let extra = match self.expansion_factor {
+            0 => 0,
+            1 => 1,
+            n => n - 1,
+        };
 
-        count += self
-            .expanded_rows
-            .iter()
-            .filter(|&&row| Self::crosses_row(row, from, to))
-            .count();
-        count += self
-            .expanded_cols
+        self.remapped = self
+            .galaxies
             .iter()
-            .filter(|&&col| Self::crosses_col(col, from, to))
-            .count();
-
-        count
-    }
-
-    fn crosses_row(row: usize, from: &Coordinate, to: &Coordinate) -> bool {
-        let lo = from.y.min(to.y);
-        let hi = from.y.max(to.y);
-        lo < row && row < hi
-    }
-
-    fn crosses_col(col: usize, from: &Coordinate, to: &Coordinate) -> bool {
-        let lo = from.x.min(to.x);
-        let hi = from.x.max(to.x);
-        lo < col && col < hi
+            .map(|c| {
+                let rows_below = self.expanded_rows.partition_point(|&row| row < c.y);
+                let cols_left = self.expanded_cols.partition_point(|&col| col < c.x);
+                let remapped = Coordinate::new(c.x + cols_left * extra, c.y + rows_below * extra);
+                (*c, remapped)
+            })
+            .collect();
     }
 }
 
@@ -120,12 +113,16 @@ impl FromStr for GalaxyMap {
             }
         }
 
-        Ok(Self {
+        let mut map = Self {
             galaxies,
             expansion_factor: 1,
             expanded_rows,
             expanded_cols,
-        })
+            remapped: HashMap::new(),
+        };
+        map.rebuild_remapped();
+
+        Ok(map)
     }
 }
 
@@ -1,13 +1,10 @@
 use std::{
-    collections::HashMap,
     fmt::{self, Write},
     str::FromStr,
 };
 
 use anyhow::{anyhow, bail};
 
-use crate::map;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Status {
     Operational,
@@ -38,7 +35,7 @@ pub struct Record {
 impl Record {
     /// Counts the total number of valid arrangements for this record.
     pub fn arrangements(&self) -> u64 {
-        Self::arrangements_impl(&self.springs, &self.counts, &mut map![])
+        Self::arrangements_impl(&self.springs, &self.counts)
     }
 
     /// Counts the total number of valid arrangements for this record when
@@ -54,36 +51,118 @@ impl Record {
         springs.pop();
         let counts = self.counts.repeat(count);
 
-        Self::arrangements_impl(&springs, &counts, &mut map![])
+        Self::arrangements_impl(&springs, &counts)
     }
 
     /// This implementation was adapted from [ropewalker]'s solution. The
     /// general strategy is to step through each column of the status and
     /// calculate different arrangements for the current sliding window.
     ///
-    /// A cache is used to store known configurations and speed up calculations
-    /// for future iterations. A tuple of `(springs, counts)` is used for a
-    /// unique key -- this is important because certain configurations might
-    /// be the same but for different stages in the `counts` phase.
+    /// Every recursive call in the original formulation operates on a
+    /// *suffix* of `springs` and `counts`, so the reachable state space is
+    /// bounded by `(springs.len() + 1) * (counts.len() + 1)`. Rather than
+    /// memoizing those suffixes in a `HashMap`, `table` is a flat `Vec`
+    /// indexed by `(spring_suffix_start, count_suffix_start)` and is filled
+    /// bottom-up: the `counts.len()` column (the base case, no groups left
+    /// to place) is computed first, then each remaining column is filled
+    /// from the one after it, since a cell only ever reads neighbors at a
+    /// later spring position and/or a later count position. This trades the
+    /// hashing overhead of the recursive cache for tight array indexing.
     ///
     /// [ropewalker]: https://github.com/ropewalker/advent_of_code_2023/blob/e3146fe35ec96684ee9004cd5896bc1d7cc38faa/src/day12.rs
-    fn arrangements_impl(
-        springs: &[Status],
-        counts: &[usize],
-        cache: &mut HashMap<(usize, usize), u64>,
-    ) -> u64 {
-        let key = (springs.len(), counts.len());
+    fn arrangements_impl(springs: &[Status], counts: &[usize]) -> u64 {
+        let n = springs.len();
+        let m = counts.len();
+
+        // has_damaged_from[p] is true if springs[p..] contains a damaged spring
+        let mut has_damaged_from = vec![false; n + 1];
+        for p in (0..n).rev() {
+            has_damaged_from[p] = has_damaged_from[p + 1] || springs[p] == Status::Damaged;
+        }
+
+        // table[p * (m + 1) + q] holds arrangements for springs[p..] and counts[q..]
+        let mut table = vec![0u64; (n + 1) * (m + 1)];
+        let idx = |p: usize, q: usize| p * (m + 1) + q;
+
+        for p in 0..=n {
+            table[idx(p, m)] = u64::from(!has_damaged_from[p]);
+        }
+
+        for q in (0..m).rev() {
+            for p in (0..=n).rev() {
+                let suffix = &springs[p..];
+                let mut count = 0;
+
+                for i in 0..suffix.len() {
+                    let j = i + counts[q];
+                    let next = j + 1;
 
-        if let Some(&value) = cache.get(&key) {
-            return value;
+                    if suffix[0..i].contains(&Status::Damaged) || j > suffix.len() {
+                        break;
+                    }
+                    if suffix[i..j].contains(&Status::Operational) {
+                        continue;
+                    }
+
+                    if q + 1 == m {
+                        if j == suffix.len() {
+                            count += 1;
+                            break;
+                        } else {
+                            count += table[idx(p + j, m)];
+                            continue;
+                        }
+                    } else if suffix.len() <= next {
+                        break;
+                    } else if suffix[j] == Status::Damaged {
+                        continue;
+                    }
+
+                    count += table[idx(p + next, q + 1)];
+                }
+
+                table[idx(p, q)] = count;
+            }
         }
+
+        table[idx(0, 0)]
+    }
+
+    /// Enumerates every concrete assignment of this record's `?` springs to
+    /// damaged/operational that is consistent with `counts`, rendered the
+    /// same way as [`Record`]'s [`Display`] impl (e.g. `#.#.###`).
+    ///
+    /// Useful for debugging, visualization, and spot-checking
+    /// [`Record::arrangements`]'s count against small inputs.
+    pub fn enumerate(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut acc = Vec::with_capacity(self.springs.len());
+        Self::enumerate_impl(&self.springs, &self.counts, &mut acc, &mut out);
+
+        out
+    }
+
+    /// Mirrors [`Record::arrangements_impl`]'s recursion, but instead of
+    /// just counting each valid group placement, fixes it (and the
+    /// operational separator after it) into `acc` and recurses on the
+    /// remainder, backfilling any trailing springs as operational once
+    /// every count has been placed.
+    fn enumerate_impl(
+        springs: &[Status],
+        counts: &[usize],
+        acc: &mut Vec<Status>,
+        out: &mut Vec<String>,
+    ) {
         if counts.is_empty() {
-            let value = u64::from(!springs.contains(&Status::Damaged));
-            cache.insert(key, value);
-            return value;
+            if !springs.contains(&Status::Damaged) {
+                let start = acc.len();
+                acc.extend(std::iter::repeat(Status::Operational).take(springs.len()));
+                out.push(Self::render(acc));
+                acc.truncate(start);
+            }
+            return;
         }
 
-        let mut count = 0;
         for i in 0..springs.len() {
             let j = i + counts[0];
             let next = j + 1;
@@ -95,26 +174,45 @@ impl Record {
                 continue;
             }
 
+            let start = acc.len();
+            acc.extend(std::iter::repeat(Status::Operational).take(i));
+            acc.extend(std::iter::repeat(Status::Damaged).take(counts[0]));
+
             if counts.len() == 1 {
                 if j == springs.len() {
-                    count += 1;
+                    out.push(Self::render(acc));
+                    acc.truncate(start);
                     break;
                 } else {
-                    count += Self::arrangements_impl(&springs[j..], &[], cache);
+                    Self::enumerate_impl(&springs[j..], &[], acc, out);
+                    acc.truncate(start);
                     continue;
-                };
+                }
             } else if springs.len() <= next {
+                acc.truncate(start);
                 break;
             } else if springs[j] == Status::Damaged {
+                acc.truncate(start);
                 continue;
             }
 
-            count += Self::arrangements_impl(&springs[next..], &counts[1..], cache);
+            acc.push(Status::Operational);
+            Self::enumerate_impl(&springs[next..], &counts[1..], acc, out);
+            acc.truncate(start);
         }
+    }
 
-        cache.insert(key, count);
-
-        count
+    /// Renders a concrete status assignment the same way [`Record`]'s
+    /// [`Display`] impl renders `self.springs`.
+    fn render(springs: &[Status]) -> String {
+        springs
+            .iter()
+            .map(|s| match s {
+                Status::Operational => '.',
+                Status::Damaged => '#',
+                Status::Unknown => '?',
+            })
+            .collect()
     }
 }
 
@@ -202,4 +300,26 @@ mod tests {
         let record = Record::from_str("?###???????? 3,2,1").unwrap();
         assert_eq!(record.arrangements_unfold(5), 506250);
     }
+
+    #[test]
+    fn record_enumerate_contents() {
+        let record = Record::from_str("???.### 1,1,3").unwrap();
+        assert_eq!(record.enumerate(), vec!["#.#.###".to_string()]);
+    }
+
+    #[test]
+    fn record_enumerate_matches_arrangements_count() {
+        let records = EXAMPLE_DATA
+            .lines()
+            .map(|line| Record::from_str(line).unwrap())
+            .collect::<Vec<_>>();
+
+        for record in records {
+            assert_eq!(
+                record.enumerate().len() as u64,
+                record.arrangements(),
+                "with record: {record}"
+            );
+        }
+    }
 }
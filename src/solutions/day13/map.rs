@@ -58,28 +58,34 @@ impl Map {
         self.grid.len()
     }
 
-    /// Finds either a row or column [`Reflection`].
+    /// Finds either a row or column [`Reflection`] with a perfect mirror (no
+    /// smudges).
     pub fn find_reflection(&self) -> Reflection {
-        // the problem states every map has a reflection line (either row or
-        // column) -- if the row method returns None, we must be able to unwrap
-        // the column value
-        self.find_reflection_row().unwrap_or_else(|| {
-            self.find_reflection_col()
+        self.find_reflection_with_smudges(0)
+    }
+
+    /// Finds either a row or column [`Reflection`] whose mirrored tiles
+    /// differ in exactly `smudges` positions.
+    ///
+    /// The problem states every map has such a reflection line (either row or
+    /// column) -- if the row method returns `None`, we must be able to unwrap
+    /// the column value.
+    pub fn find_reflection_with_smudges(&self, smudges: usize) -> Reflection {
+        self.find_reflection_row(smudges).unwrap_or_else(|| {
+            self.find_reflection_col(smudges)
                 .expect("no row reflection found, grid must have a column reflection")
         })
     }
 
-    /// Finds a reflection row in the map.
+    /// Finds a reflection row whose mirrored rows differ in exactly
+    /// `smudges` tiles in total.
     ///
     /// The basic logic is to take an index into each row, then use two windows
-    /// on each side of the index. We can compare slices to make sure each
-    /// reflected row matches. Using iterators makes this quite simple, since we
-    /// can utilize `rev` and `zip` to clamp how many rows need to match over
-    /// the reflection point.
-    ///
-    /// The total matches we need is the minimum amount of elements contained in
-    /// either of the two windows.
-    fn find_reflection_row(&self) -> Option<Reflection> {
+    /// on each side of the index. We can sum mismatched tiles across each
+    /// reflected row pair. Using iterators makes this quite simple, since we
+    /// can utilize `rev` and `zip` to clamp how many rows need to be compared
+    /// over the reflection point.
+    fn find_reflection_row(&self, smudges: usize) -> Option<Reflection> {
         for i in 0..self.height() {
             let top = 0..i;
             let bottom = i..self.height();
@@ -90,15 +96,14 @@ impl Map {
                 continue;
             }
 
-            let mut matches = 0;
-            for (r1, r2) in self.grid[top].iter().rev().zip(&self.grid[bottom]) {
-                if r1 != r2 {
-                    break;
-                }
-                matches += 1;
-            }
+            let mismatches: usize = self.grid[top]
+                .iter()
+                .rev()
+                .zip(&self.grid[bottom])
+                .map(|(r1, r2)| r1.iter().zip(r2).filter(|(a, b)| a != b).count())
+                .sum();
 
-            if matches == required {
+            if mismatches == smudges {
                 return Some(Reflection::Row(i));
             }
         }
@@ -106,13 +111,14 @@ impl Map {
         None
     }
 
-    /// Finds a reflection column in the map.
+    /// Finds a reflection column whose mirrored columns differ in exactly
+    /// `smudges` tiles in total.
     ///
     /// This method follows the same logic as the row counterpart, but is
     /// implemented slightly differently since we cannot slice columns from
     /// nested vectors. This could probably be easier with a crate like
     /// `ndarray`, but it's good practice to manually implement this logic.
-    fn find_reflection_col(&self) -> Option<Reflection> {
+    fn find_reflection_col(&self, smudges: usize) -> Option<Reflection> {
         for i in 0..self.width() {
             let left = 0..i;
             let right = i..self.width();
@@ -122,17 +128,13 @@ impl Map {
                 continue;
             }
 
-            let mut matches = 0;
-            for (c1, c2) in left.rev().zip(right) {
-                // instead of comparing slices, we can just check if all
-                // elements in each row are equal at the specified columns
-                if !self.grid.iter().all(|row| row[c1] == row[c2]) {
-                    break;
-                }
-                matches += 1;
-            }
+            let mismatches: usize = left
+                .rev()
+                .zip(right)
+                .map(|(c1, c2)| self.grid.iter().filter(|row| row[c1] != row[c2]).count())
+                .sum();
 
-            if matches == required {
+            if mismatches == smudges {
                 return Some(Reflection::Column(i));
             }
         }
@@ -226,8 +228,8 @@ mod tests {
 
         for (input, expected_r, expected_c) in data {
             let m = Map::from_str(input).unwrap();
-            assert_eq!(m.find_reflection_row(), expected_r);
-            assert_eq!(m.find_reflection_col(), expected_c);
+            assert_eq!(m.find_reflection_row(0), expected_r);
+            assert_eq!(m.find_reflection_col(0), expected_c);
         }
     }
 
@@ -240,4 +242,29 @@ mod tests {
             assert_eq!(m.find_reflection().summarize(), expected);
         }
     }
+
+    #[test]
+    fn map_find_reflection_with_one_smudge() {
+        // has the form (input, expected row reflect, expected col reflect)
+        let data = vec![
+            (EXAMPLE_MAP_H, Some(Reflection::Row(1)), None),
+            (EXAMPLE_MAP_V, Some(Reflection::Row(3)), None),
+        ];
+
+        for (input, expected_r, expected_c) in data {
+            let m = Map::from_str(input).unwrap();
+            assert_eq!(m.find_reflection_row(1), expected_r);
+            assert_eq!(m.find_reflection_col(1), expected_c);
+        }
+    }
+
+    #[test]
+    fn map_find_reflection_with_smudges_summarize() {
+        let data = vec![(EXAMPLE_MAP_H, 100), (EXAMPLE_MAP_V, 300)];
+
+        for (input, expected) in data {
+            let m = Map::from_str(input).unwrap();
+            assert_eq!(m.find_reflection_with_smudges(1).summarize(), expected);
+        }
+    }
 }
@@ -0,0 +1,85 @@
+//! Solution for Advent of Code 2023, Day 13.
+//!
+//! # Day 13: Point of Incidence
+//!
+//! Each input block is a map of ash and rock with a single line of
+//! reflection, either horizontal or vertical; part 1 sums each map's
+//! perfect-mirror reflection, while part 2 sums the reflection produced by
+//! fixing exactly one "smudged" tile.
+
+mod map;
+
+use anyhow::Result;
+
+pub use self::map::{Map, Reflection};
+use crate::solution::Solution;
+
+/// Parses `input` into one [`Map`] per blank-line-separated block.
+fn parse_maps(input: &str) -> Result<Vec<Map>> {
+    input.split("\n\n").map(str::parse).collect()
+}
+
+/// [`Solution`] implementation for this day.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(input: &str) -> Result<Self::Answer1> {
+        let maps = parse_maps(input)?;
+        Ok(maps
+            .iter()
+            .map(|m| m.find_reflection().summarize())
+            .sum())
+    }
+
+    fn part2(input: &str) -> Result<Self::Answer2> {
+        let maps = parse_maps(input)?;
+        Ok(maps
+            .iter()
+            .map(|m| m.find_reflection_with_smudges(1).summarize())
+            .sum())
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+impl crate::solution::Problem for Day13 {
+    fn input() -> &'static str {
+        crate::embed_input!(13)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        #.##..##.\n\
+        ..#.##.#.\n\
+        ##......#\n\
+        ##......#\n\
+        ..#.##.#.\n\
+        ..##..##.\n\
+        #.#.##.#.\n\
+        \n\
+        #...##..#\n\
+        #....#..#\n\
+        ..##..###\n\
+        #####.##.\n\
+        #####.##.\n\
+        ..##..###\n\
+        #....#..#";
+
+    #[test]
+    fn day13_part1() {
+        assert_eq!(Day13::part1(EXAMPLE).unwrap(), 405);
+    }
+
+    #[test]
+    fn day13_part2() {
+        assert_eq!(Day13::part2(EXAMPLE).unwrap(), 400);
+    }
+}
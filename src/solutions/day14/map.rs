@@ -4,7 +4,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::{map, Coordinate, Direction, Grid};
+use crate::{map, Direction, Grid};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Tile {
@@ -77,84 +77,124 @@ pub struct Map {
 }
 
 impl Map {
+    /// Rolls every [`Tile::Rounded`] tile as far as it can go in the given
+    /// `dir`, in a single O(width*height) pass.
+    ///
+    /// For each line parallel to `dir` (a column for North/South, a row for
+    /// East/West), walks the line starting from the wall the rocks pile up
+    /// against, keeping a `next_free` pointer: [`Tile::Cube`] resets it to
+    /// just past the cube, and each [`Tile::Rounded`] found is swapped into
+    /// it (if it isn't already there) before the pointer advances.
     pub fn tilt(&mut self, dir: Direction) {
-        // this is a naive solution where we keep iterating over the board and
-        // move every rock until none can move anymore. a better solution would
-        // be to iterate opposite of the move direction (e.g., if moving west,
-        // iterate over columns, right-to-left) and push each rock as far as
-        // they can go at one time. among other issues, this would avoid a full
-        // "empty" pass of the board at the end.
-        let mut running = true;
-        while running {
-            let mut moved = false;
-            for i in 0..self.grid.len() {
-                let pos = Coordinate::from_index(i, self.grid.width());
-                if self.grid[pos] != Tile::Rounded {
-                    continue;
+        let width = self.grid.width();
+        let height = self.grid.height();
+
+        match dir {
+            Direction::North => {
+                for x in 0..width {
+                    let mut next_free = 0;
+                    for y in 0..height {
+                        match self.grid[(x, y)] {
+                            Tile::Cube => next_free = y + 1,
+                            Tile::Rounded => {
+                                if next_free != y {
+                                    self.grid.swap((x, y), (x, next_free));
+                                }
+                                next_free += 1;
+                            }
+                            Tile::Empty => {}
+                        }
+                    }
+                }
+            }
+            Direction::South => {
+                for x in 0..width {
+                    // wraps to `usize::MAX` past the top edge, but that's
+                    // only ever compared against, never indexed: a cube at
+                    // `y == 0` leaves no rounded tile above it to place.
+                    let mut next_free = height - 1;
+                    for y in (0..height).rev() {
+                        match self.grid[(x, y)] {
+                            Tile::Cube => next_free = y.wrapping_sub(1),
+                            Tile::Rounded => {
+                                if next_free != y {
+                                    self.grid.swap((x, y), (x, next_free));
+                                }
+                                next_free = next_free.wrapping_sub(1);
+                            }
+                            Tile::Empty => {}
+                        }
+                    }
+                }
+            }
+            Direction::West => {
+                for y in 0..height {
+                    let mut next_free = 0;
+                    for x in 0..width {
+                        match self.grid[(x, y)] {
+                            Tile::Cube => next_free = x + 1,
+                            Tile::Rounded => {
+                                if next_free != x {
+                                    self.grid.swap((x, y), (next_free, y));
+                                }
+                                next_free += 1;
+                            }
+                            Tile::Empty => {}
+                        }
+                    }
+                }
+            }
+            Direction::East => {
+                for y in 0..height {
+                    // see the North/South `wrapping_sub` note above.
+                    let mut next_free = width - 1;
+                    for x in (0..width).rev() {
+                        match self.grid[(x, y)] {
+                            Tile::Cube => next_free = x.wrapping_sub(1),
+                            Tile::Rounded => {
+                                if next_free != x {
+                                    self.grid.swap((x, y), (next_free, y));
+                                }
+                                next_free = next_free.wrapping_sub(1);
+                            }
+                            Tile::Empty => {}
+                        }
+                    }
                 }
-                moved |= self.apply_force(pos, dir);
             }
-            running &= moved;
         }
     }
 
-    pub fn spin_cycle(&mut self, mut count: usize) {
-        if count == 0 {
-            return;
-        }
-
+    /// Runs `count` spin cycles (a North, West, South, then East [`Map::tilt`]),
+    /// detecting the board's period so huge counts (e.g. a billion) don't
+    /// require actually running that many cycles.
+    ///
+    /// Keeps a `HashMap<Grid<Tile>, usize>` mapping each board state to the
+    /// index it was first seen at. Before running cycle `i`, if the current
+    /// state was already seen at index `first`, the board is periodic with
+    /// period `i - first`: the remaining `(count - i) % (i - first)` cycles
+    /// are run directly, and the result is exact, since every state from
+    /// `first` onward recurs every `period` cycles.
+    pub fn spin_cycle(&mut self, count: usize) {
         use Direction::*;
-        type Ty = Grid<Tile>;
         let dirs = [North, West, South, East];
 
-        let mut states: HashMap<Ty, Ty> = map![];
-        let mut queue = Some(vec![]);
-
-        while count > 0 {
-            count -= 1;
-
-            // this is the meat and potatoes of the solution for high spin
-            // cycles (e.g., 1 billion). we first check if this current state is
-            // in the cache. if this state has a key, we can check for a "cycle"
-            // or pattern of states.
-            //
-            // each state that is found in the cache is pushed onto the queue so
-            // that we can count the length of the pattern when we come back to
-            // the 0-th state. this is really wasteful on memory and can be
-            // improved by splitting this up into different loops (e.g., a
-            // "searching" loop and a "finish" loop after using the cycle length
-            // modulus).
-            //
-            // for now, this solution works, but should definitely be improved.
-            if let Some(grid) = states.get(&self.grid) {
-                // note that this is a naive and faulty check since we don't
-                // verify the full length of the cycle (e.g., this 0-th state
-                // could appear several times in a single "cycle").
-                if let Some(q) = queue.as_mut() {
-                    if !q.is_empty() && q[0] == self.grid {
-                        count %= q.len();
-                        // setting the queue to None here prevents any further
-                        // pattern checks. we don't need to track states after
-                        // we found a cycle
-                        queue = None;
-                    } else {
-                        q.push(self.grid.clone());
-                    }
-                }
+        let mut seen: HashMap<Grid<Tile>, usize> = map![];
+        let mut i = 0;
 
-                self.grid = grid.clone();
-                continue;
+        while i < count {
+            if let Some(&first) = seen.get(&self.grid) {
+                let period = i - first;
+                let remaining = (count - i) % period;
+                for _ in 0..remaining {
+                    dirs.iter().for_each(|&d| self.tilt(d));
+                }
+                return;
             }
 
-            // if the current state was not in the cache, run the actual spin
-            // cycle. we can then store the result in the cache with the current
-            // grid as the key.
-            let key = self.grid.clone();
+            seen.insert(self.grid.clone(), i);
             dirs.iter().for_each(|&d| self.tilt(d));
-            states.insert(key, self.grid.clone());
-            if let Some(queue) = queue.as_mut() {
-                queue.clear();
-            };
+            i += 1;
         }
     }
 
@@ -173,27 +213,6 @@ impl Map {
                 value + if tile == Tile::Rounded { beam } else { 0 }
             })
     }
-
-    /// Checks if the tile at the `pos` can be moved, and swaps it with the tile
-    /// in the given `dir`.
-    ///
-    /// The method returns `true` if a tile was moved, and `false` otherwise.
-    fn apply_force<C: Into<Coordinate>>(&mut self, pos: C, dir: Direction) -> bool {
-        let c1: Coordinate = pos.into();
-
-        if self.grid[c1] != Tile::Rounded {
-            return false;
-        }
-
-        if let Some(c2) = c1.by_direction(dir) {
-            if let Some(&Tile::Empty) = self.grid.get(c2) {
-                self.grid.swap(c1, c2);
-                return true;
-            }
-        }
-
-        false
-    }
 }
 
 impl FromStr for Map {
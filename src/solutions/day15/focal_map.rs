@@ -114,6 +114,20 @@ impl Default for FocalMap {
     }
 }
 
+/// Runs a comma-separated initialization sequence (e.g.
+/// `"rn=1,cm-,qp=3,..."`) through a fresh [`FocalMap`] and returns its total
+/// focusing power -- the Part 2 answer for callers that just want the number
+/// from raw puzzle input.
+pub fn focusing_power(input: &str) -> usize {
+    let mut map = FocalMap::new();
+    for step in input.trim().split(',') {
+        let step: Step = step.parse().expect("valid initialization sequence step");
+        map.execute(&step);
+    }
+
+    map.focusing_power()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,4 +190,10 @@ mod tests {
         m.execute_many(steps.into_iter());
         assert_eq!(m.focusing_power(), 145);
     }
+
+    #[test]
+    fn focusing_power_matches_example() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(focusing_power(input), 145);
+    }
 }
@@ -1,3 +1,5 @@
+use std::io;
+
 /// A hash builder for the Holiday ASCII String Helper (HASH) algorithm.
 #[derive(Debug, Default)]
 pub struct Hasher {
@@ -15,15 +17,36 @@ impl Hasher {
         self.state = ((self.state + (c as usize)) * 17) % 256;
     }
 
+    /// Combines a single input byte with the current state.
+    ///
+    /// The HASH spec is defined over ASCII bytes. Only [`Hasher::combine_bytes`]
+    /// (and, through it, the [`io::Write`] impl) is built on this;
+    /// [`Hasher::combine`] and [`Hasher::combine_str`] hash each `char` by
+    /// its `as usize` code point instead.
+    fn combine_byte_impl(&mut self, b: u8) {
+        self.state = ((self.state + (b as usize)) * 17) % 256;
+    }
+
     /// Hashes all characters yielded by the [`Iterator`].
     ///
     /// This method modifies the internal state, and does not reset before or
-    /// after hashing. To reset the internal state, use [`Hasher::finalize`].
+    /// after hashing. To reset the internal state, use [`Hasher::finalize`]
+    /// or [`Hasher::reset`].
     pub fn combine<C: Iterator<Item = char>>(&mut self, data: C) -> &mut Self {
         data.for_each(|c| self.combine_impl(c));
         self
     }
 
+    /// Hashes all bytes yielded by the [`Iterator`], for streaming input that
+    /// isn't already materialized as `char`s, e.g. from a `Read` or `&[u8]`.
+    ///
+    /// Like [`Hasher::combine`], this does not reset the state before or
+    /// after hashing.
+    pub fn combine_bytes<I: Iterator<Item = u8>>(&mut self, bytes: I) -> &mut Self {
+        bytes.for_each(|b| self.combine_byte_impl(b));
+        self
+    }
+
     /// Hashes all characters of the input string.
     ///
     /// For a more generic method accepting any iterator yielding `char`, see
@@ -32,14 +55,41 @@ impl Hasher {
         self.combine(s.as_ref().chars())
     }
 
+    /// Returns the current state without resetting it.
+    ///
+    /// Unlike [`Hasher::finalize`], this lets a caller peek at the running
+    /// hash mid-stream.
+    pub fn current(&self) -> usize {
+        self.state
+    }
+
+    /// Resets the internal state to zero without returning it.
+    ///
+    /// For resetting and reading the state in one step, see
+    /// [`Hasher::finalize`].
+    pub fn reset(&mut self) {
+        self.state = 0;
+    }
+
     /// Returns the current state and resets it.
     pub fn finalize(&mut self) -> usize {
-        let value = self.state;
-        self.state = 0;
+        let value = self.current();
+        self.reset();
         value
     }
 }
 
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.combine_bytes(buf.iter().copied());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Shorthand to calculate the HASH of a `&str` with [`Hasher`] and return the
 /// value.
 ///
@@ -83,4 +133,44 @@ mod tests {
         assert_eq!(h.state, 0);
         assert_eq!(sum, 1320);
     }
+
+    #[test]
+    fn hasher_combine_bytes_matches_combine_str() {
+        let mut by_bytes = Hasher::new();
+        by_bytes.combine_bytes("HASH".bytes());
+
+        let mut by_str = Hasher::new();
+        by_str.combine_str("HASH");
+
+        assert_eq!(by_bytes.finalize(), by_str.finalize());
+    }
+
+    #[test]
+    fn hasher_current_does_not_reset() {
+        let mut h = Hasher::new();
+        h.combine_str("HASH");
+
+        assert_eq!(h.current(), 52);
+        assert_eq!(h.current(), 52);
+        assert_eq!(h.finalize(), 52);
+    }
+
+    #[test]
+    fn hasher_reset_zeroes_state_without_returning_it() {
+        let mut h = Hasher::new();
+        h.combine_str("HASH");
+        h.reset();
+
+        assert_eq!(h.current(), 0);
+    }
+
+    #[test]
+    fn hasher_write_impl_combines_bytes() {
+        use std::io::Write;
+
+        let mut h = Hasher::new();
+        write!(h, "HASH").unwrap();
+
+        assert_eq!(h.finalize(), 52);
+    }
 }
@@ -1,6 +1,10 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
 
-use crate::{Coordinate, Direction, Grid};
+use crate::{dijkstra::Graph, Coordinate, Direction, Grid};
 
 /// Represents a tile in the cave grid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,6 +53,55 @@ impl Beam {
     }
 }
 
+/// A dense bitset over every tile of a [`Map`]'s grid, used by
+/// [`Map::beam_components`] to track a beam-state component's reachable
+/// tiles without a `HashSet<Coordinate>` per component.
+#[derive(Debug, Clone)]
+struct TileSet {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl TileSet {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            words: vec![0u64; (width * height).div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, pos: Coordinate) {
+        let i = pos.to_index(self.width);
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
+/// [`Map::beam_components`]'s result: every beam state's strongly connected
+/// component index, plus each component's precomputed reachable-tile set, so
+/// [`Map::trace_cached`]/[`Map::trace_max`] can answer a tile count with a
+/// lookup instead of a fresh DFS.
+struct BeamComponents {
+    component_of: HashMap<Beam, usize>,
+    sets: Vec<TileSet>,
+}
+
+impl BeamComponents {
+    /// The number of tiles reachable from `start`'s beam-state component.
+    fn count(&self, start: &Beam) -> usize {
+        self.sets[self.component_of[start]].count()
+    }
+}
+
 /// A contraption consisting of mirrors and splitters that focus a [`Beam`] to
 /// energize tiles in the cave.
 pub struct Map {
@@ -62,13 +115,24 @@ impl Map {
         direction: Direction::East,
     };
 
-    /// Traces a beam of light through the contraption using DFS and returns the
-    /// number of energized tiles.
+    /// Traces a beam of light through the contraption using DFS and returns
+    /// the number of energized tiles.
     ///
     /// # Panics
     ///
     /// Panics if the starting [`Beam`] position is out of bounds.
     pub fn trace(&self, start: Beam) -> usize {
+        self.trace_energized(start).count()
+    }
+
+    /// Traces a beam of light through the contraption using DFS and returns
+    /// every tile it energizes, along with the set of directions the beam
+    /// passed through it in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting [`Beam`] position is out of bounds.
+    pub fn trace_energized(&self, start: Beam) -> Energized {
         assert!(self.grid.contains(start.pos));
 
         let mut beams = Vec::from([start]);
@@ -76,6 +140,7 @@ impl Map {
         // can cause cycles. we also need to let beams overlap if they are
         // moving in different directions over the same tile.
         let mut visited = HashSet::new();
+        let mut directions = vec![0u8; self.grid.width() * self.grid.height()];
 
         while let Some(b) = beams.pop() {
             // IMPORTANT: check grid contains first to short circuit
@@ -83,11 +148,25 @@ impl Map {
                 continue;
             }
 
+            directions[b.pos.to_index(self.grid.width())] |= Energized::direction_bit(b.direction);
             beams.extend(self.energize(b).into_iter().filter_map(|b| self.advance(b)));
         }
 
-        // we need to only count coordinates for result
-        HashSet::<Coordinate>::from_iter(visited.into_iter().map(|b| b.pos)).len()
+        Energized {
+            map: self,
+            directions,
+        }
+    }
+
+    /// Cached counterpart to [`Map::trace`]: the number of tiles `start`
+    /// energizes, read from a single precomputed bitset instead of a fresh
+    /// DFS.
+    ///
+    /// This still pays for the full [`Map::beam_components`] precomputation
+    /// on every call, so prefer [`Map::trace_max`] (which precomputes it
+    /// once for every edge start) when checking more than one beam.
+    pub fn trace_cached(&self, start: Beam) -> usize {
+        self.beam_components().count(&start)
     }
 
     /// Traces every beam of light from the edge of the map facing inward and
@@ -96,25 +175,77 @@ impl Map {
     /// For example, a beam of light starting on the south edge will face north,
     /// right edge will face west, etc.
     ///
-    /// This is a purely brute force solution and does not use any kind of
-    /// memoization or caching.
+    /// Precomputes [`Map::beam_components`] once and looks up every edge
+    /// start's count in it, rather than re-tracing the (heavily overlapping)
+    /// interior paths from scratch for each one.
     pub fn trace_max(&self) -> usize {
+        let components = self.beam_components();
         let mut count = 0;
 
         let y_max = self.grid.height() - 1;
         for x in 0..self.grid.width() {
-            count = count.max(self.trace(Beam::new((x, 0), Direction::South)));
-            count = count.max(self.trace(Beam::new((x, y_max), Direction::North)));
+            count = count.max(components.count(&Beam::new((x, 0), Direction::South)));
+            count = count.max(components.count(&Beam::new((x, y_max), Direction::North)));
         }
         let x_max = self.grid.width() - 1;
         for y in 0..self.grid.height() {
-            count = count.max(self.trace(Beam::new((1, y), Direction::East)));
-            count = count.max(self.trace(Beam::new((x_max, y), Direction::East)));
+            count = count.max(components.count(&Beam::new((0, y), Direction::East)));
+            count = count.max(components.count(&Beam::new((x_max, y), Direction::West)));
         }
 
         count
     }
 
+    /// Builds the complete beam-state transition graph (nodes are
+    /// `(Coordinate, Direction)` [`Beam`]s, edges are one [`Map::energize`]
+    /// plus [`Map::advance`] step), condenses it into a DAG via
+    /// [`Graph::tarjan_scc`] (splitter loops are the only source of cycles),
+    /// and computes every component's reachable-tile set with one
+    /// reverse-topological pass.
+    ///
+    /// [`Graph::tarjan_scc`] already returns components in reverse
+    /// topological order -- a component is only finished once every
+    /// component it has an edge to has already been finished -- so a single
+    /// forward pass over its output computes each [`TileSet`] exactly once,
+    /// unioning in already-finished successor components instead of
+    /// re-deriving them.
+    fn beam_components(&self) -> BeamComponents {
+        let width = self.grid.width();
+        let height = self.grid.height();
+
+        let all_states = (0..width).flat_map(|x| {
+            (0..height).flat_map(move |y| Direction::iter().map(move |d| Beam::new((x, y), d)))
+        });
+        let sccs = self.tarjan_scc(all_states);
+
+        let mut component_of = HashMap::new();
+        for (i, component) in sccs.iter().enumerate() {
+            for beam in component {
+                component_of.insert(beam.clone(), i);
+            }
+        }
+
+        let mut sets: Vec<TileSet> = Vec::with_capacity(sccs.len());
+        for component in &sccs {
+            let mut set = TileSet::new(width, height);
+            for beam in component {
+                set.insert(beam.pos);
+                for succ in self.adjacent(beam) {
+                    let j = component_of[&succ];
+                    // `j`'s set is already final: reverse-topological order
+                    // guarantees successor components are finished first
+                    if j != sets.len() {
+                        let successor_set = sets[j].clone();
+                        set.union_with(&successor_set);
+                    }
+                }
+            }
+            sets.push(set);
+        }
+
+        BeamComponents { component_of, sets }
+    }
+
     /// Moves the beam one tile in it's current facing direction.
     ///
     /// Returns [`None`] if the next position is invalid or out of bounds.
@@ -194,6 +325,97 @@ impl Map {
     }
 }
 
+/// The result of [`Map::trace_energized`]: every tile's accumulated set of
+/// beam directions, dense over the grid so it can be rendered directly
+/// against the original contraption via [`Display`][fmt::Display].
+pub struct Energized<'m> {
+    map: &'m Map,
+    // one bit per `Direction`, indexed by `Direction::ALL`'s position
+    directions: Vec<u8>,
+}
+
+impl<'m> Energized<'m> {
+    fn direction_bit(direction: Direction) -> u8 {
+        let i = Direction::ALL.iter().position(|&d| d == direction).unwrap();
+        1 << i
+    }
+
+    /// The energized [`Coordinate`]s, regardless of which direction a beam
+    /// passed through them in.
+    pub fn positions(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        let width = self.map.grid.width();
+        self.directions
+            .iter()
+            .enumerate()
+            .filter(|(_, &bits)| bits != 0)
+            .map(move |(i, _)| Coordinate::from_index(i, width))
+    }
+
+    /// The number of tiles with at least one beam direction recorded.
+    pub fn count(&self) -> usize {
+        self.directions.iter().filter(|&&bits| bits != 0).count()
+    }
+}
+
+impl<'m> fmt::Display for Energized<'m> {
+    /// Overlays this trace on the original map: an energized [`Tile::Empty`]
+    /// shows `^`/`>`/`v`/`<` where exactly one beam direction passed through
+    /// it, or a digit counting the directions where more than one did;
+    /// mirrors, splitters and un-energized tiles keep their original
+    /// character.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.map.grid.width();
+        let height = self.map.grid.height();
+
+        for y in 0..height {
+            for x in 0..width {
+                let pos = Coordinate::from((x, y));
+                let bits = self.directions[pos.to_index(width)];
+
+                let tile = self.map.grid[pos];
+                let c = match bits.count_ones() {
+                    _ if tile != Tile::Empty => tile_char(tile),
+                    0 => tile_char(tile),
+                    1 => {
+                        let i = bits.trailing_zeros() as usize;
+                        direction_char(Direction::ALL[i])
+                    }
+                    n => char::from_digit(n, 10).unwrap(),
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The arrow this direction renders as in [`Energized`]'s [`Display`][fmt::Display] impl.
+fn direction_char(direction: Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+/// The inverse of [`Tile::from`][<Tile as From<char>>::from], for rendering
+/// an un-energized [`Tile`] back to its original character in [`Energized`]'s
+/// [`Display`][fmt::Display] impl.
+fn tile_char(tile: Tile) -> char {
+    use Tile::*;
+
+    match tile {
+        Empty => '.',
+        MirrorForward => '/',
+        MirrorBack => '\\',
+        SplitterH => '-',
+        SplitterV => '|',
+    }
+}
+
 impl FromStr for Map {
     type Err = anyhow::Error;
 
@@ -204,6 +426,25 @@ impl FromStr for Map {
     }
 }
 
+/// Exposes the beam-state transition graph to [`Graph::tarjan_scc`] for
+/// [`Map::beam_components`]; distances are never actually used, since this
+/// `Map` only needs the graph's structure, not shortest paths over it.
+impl Graph for Map {
+    type Node = Beam;
+    type Distance = usize;
+
+    fn adjacent(&self, node: &Self::Node) -> Vec<Self::Node> {
+        self.energize(node.clone())
+            .into_iter()
+            .filter_map(|b| self.advance(b))
+            .collect()
+    }
+
+    fn edge(&self, _from: &Self::Node, _to: &Self::Node) -> Self::Distance {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,4 +478,40 @@ mod tests {
         let m = Map::from_str(EXAMPLE_MAP).unwrap();
         assert_eq!(m.trace_max(), 51);
     }
+
+    #[test]
+    fn map_trace_cached_matches_dfs() {
+        let m = Map::from_str(EXAMPLE_MAP).unwrap();
+        assert_eq!(m.trace_cached(Map::STARTING_BEAM), 46);
+        assert_eq!(m.trace_cached(Map::STARTING_BEAM), m.trace(Map::STARTING_BEAM));
+    }
+
+    #[test]
+    fn trace_energized_count_matches_trace() {
+        let m = Map::from_str(EXAMPLE_MAP).unwrap();
+        let energized = m.trace_energized(Map::STARTING_BEAM);
+
+        assert_eq!(energized.count(), 46);
+        assert_eq!(energized.positions().count(), 46);
+    }
+
+    #[test]
+    fn trace_energized_renders_ascii_overlay() {
+        let expected = r#">|<<<\....
+|v-.\^....
+.v...|->>>
+.v...v^.|.
+.v...v^...
+.v...v^..\
+.v../2\\..
+<->-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+"#;
+
+        let m = Map::from_str(EXAMPLE_MAP).unwrap();
+        let energized = m.trace_energized(Map::STARTING_BEAM);
+
+        assert_eq!(energized.to_string(), expected);
+    }
 }
@@ -1,4 +1,10 @@
-use std::{fmt, hash::Hash, marker::PhantomData, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Write},
+    hash::Hash,
+    marker::PhantomData,
+    str::FromStr,
+};
 
 use crate::{dijkstra::Graph, Coordinate, Direction, Grid};
 
@@ -62,52 +68,26 @@ impl From<Coordinate> for Node {
     }
 }
 
-/// Marker type that implements regular crucible traversal logic for a [`City`].
-pub struct Crucible;
-
-impl DijkstraExt for Crucible {
-    fn adjacent(node: &Node, city: &City<Self>) -> Vec<Node> {
-        // movement restrictions:
-        // - maximum of 3 in same direction
-        // - can't move backwards
-
-        let mut nodes = vec![];
-        for d in Direction::ALL {
-            if d == node.dir.opposite() || (node.dir == d && node.count >= 3) {
-                continue;
-            }
-            if let Some(pos) = node.pos.by_direction(d) {
-                if !city.grid.contains(pos) {
-                    continue;
-                }
-                let count = 1 + if node.dir == d { node.count } else { 0 };
-                nodes.push(Node::new(pos, d, count));
-            }
-        }
-
-        nodes
-    }
-}
-
-/// Marker type that implements ultra crucible traversal logic for a [`City`].
-pub struct UltraCrucible;
-
-impl DijkstraExt for UltraCrucible {
+/// Marker type parameterizing crucible movement rules by their minimum and
+/// maximum consecutive-step counts, via const generics.
+///
+/// Movement restrictions:
+/// - can't move backwards
+/// - can't continue straight once `MAX` consecutive steps have been taken
+/// - can't turn (or stop) until at least `MIN` consecutive steps have been
+///   taken
+///
+/// `node.count == 0` (the starting node, which has no direction yet) is
+/// exempt from the `MIN` turn restriction, since it hasn't moved at all.
+pub struct CrucibleRules<const MIN: u8, const MAX: u8>;
+
+impl<const MIN: u8, const MAX: u8> DijkstraExt for CrucibleRules<MIN, MAX> {
     fn adjacent(node: &Node, city: &City<Self>) -> Vec<Node> {
-        // movement restrictions:
-        // - minimum of 4 in same direction
-        // - maximum of 10 in same direction
-        // - can't move backwards
-        // NOTE: this implementation needs to account for the starting node
-        // which will have a direction with a count of 0; it shouldn't be forced
-        // to continue in the starting direction. all other nodes will have at
-        // least 1 movement.
-
         let mut nodes = vec![];
         for d in Direction::ALL {
             if d == node.dir.opposite()
-                || (node.dir == d && node.count >= 10)
-                || (node.dir != d && node.count < 4 && node.count > 0)
+                || (node.dir == d && node.count >= MAX)
+                || (node.dir != d && node.count < MIN && node.count > 0)
             {
                 continue;
             }
@@ -128,12 +108,19 @@ impl DijkstraExt for UltraCrucible {
         // > Once an ultra crucible starts moving in a direction, it needs to
         // > move a minimum of four blocks in that direction before it can turn
         // > **(or even before it can stop at the end)**
-        // thus, the search cannot be considered complete if it has not moved at
-        // least 4 times in the current direction
-        node.count >= 4
+        // thus, the search cannot be considered complete if it has not moved
+        // at least `MIN` times in the current direction
+        node.count >= MIN
     }
 }
 
+/// A crucible that can move up to 3 consecutive blocks before turning.
+pub type Crucible = CrucibleRules<1, 3>;
+
+/// An "ultra crucible" that must move at least 4, and at most 10, consecutive
+/// blocks before it can turn or stop.
+pub type UltraCrucible = CrucibleRules<4, 10>;
+
 /// A map of the city blocks, containing heat loss information in each cell.
 #[derive(Debug)]
 pub struct City<T: DijkstraExt> {
@@ -151,7 +138,84 @@ impl<T: DijkstraExt> City<T> {
     /// Traverses the city from top-left to bottom-right, following pathing
     /// rules of this city's crucible.
     pub fn traverse(&self) -> usize {
-        self.min_distance(Self::START, self.bottom_right())
+        let goal = Node::from(self.bottom_right());
+        let map = self.astar(Self::START, goal);
+
+        map.distances()[&map.to]
+    }
+
+    /// Same as [`City::traverse`], but also returns the ordered coordinates
+    /// of the path the crucible actually took, reconstructed from the
+    /// search's predecessor chain.
+    pub fn traverse_path(&self) -> (usize, Vec<Coordinate>) {
+        let goal = Node::from(self.bottom_right());
+        let map = self.astar(Self::START, goal);
+
+        let cost = map.distances()[&map.to];
+        let path = map
+            .path()
+            .expect("goal must be reachable after a completed search")
+            .into_iter()
+            .map(|n| n.pos)
+            .collect();
+
+        (cost, path)
+    }
+
+    /// Renders this city's grid as text, replacing every coordinate in
+    /// `path` with `#`, mirroring how reference implementations dump the
+    /// traversed route over the grid.
+    pub fn render_path(&self, path: &[Coordinate]) -> String {
+        let marks: HashSet<Coordinate> = path.iter().copied().collect();
+        let mut out = String::new();
+
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                let pos = Coordinate::new(x, y);
+                if marks.contains(&pos) {
+                    out.push('#');
+                } else {
+                    write!(out, "{}", self.grid[pos]).expect("writing to a String never fails");
+                }
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Builds a directed, weighted [`petgraph::Graph`] over every [`Node`]
+    /// state reachable from [`City::START`][Self::START] under this city's
+    /// crucible rules, with each edge weighted by the destination block's
+    /// heat loss, plus a lookup from each [`Node`] to its node index.
+    ///
+    /// This hands the search space over to the broader petgraph toolbox
+    /// (alternative or k-shortest-path algorithms, DOT export for
+    /// visualization) without reimplementing it here; [`City::traverse`]
+    /// remains the default fast path.
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(
+        &self,
+    ) -> (
+        petgraph::Graph<Node, usize>,
+        HashMap<Node, petgraph::graph::NodeIndex>,
+    ) {
+        let mut graph = petgraph::Graph::new();
+        let mut indices = HashMap::new();
+        indices.insert(Self::START, graph.add_node(Self::START));
+
+        let mut queue = VecDeque::from([Self::START]);
+        while let Some(node) = queue.pop_front() {
+            for next in T::adjacent(&node, self) {
+                let to = *indices.entry(next).or_insert_with(|| {
+                    queue.push_back(next);
+                    graph.add_node(next)
+                });
+                graph.add_edge(indices[&node], to, self.grid[next.pos].weight());
+            }
+        }
+
+        (graph, indices)
     }
 
     /// Consumes this [`City`] and changes the crucible marker type.
@@ -191,6 +255,14 @@ impl<T: DijkstraExt> Graph for City<T> {
         // finished, even if destination coordinates are a match
         T::is_done(current)
     }
+
+    /// The Manhattan distance from `node` to `to`. It never overestimates
+    /// the true remaining cost (so it is admissible) as long as every block
+    /// has a heat loss of at least `1`; [`Block`] also accepts `0`, and a
+    /// grid with `0` blocks can make it overestimate.
+    fn heuristic(&self, node: &Self::Node, to: &Self::Node) -> Self::Distance {
+        node.pos.x.abs_diff(to.pos.x) + node.pos.y.abs_diff(to.pos.y)
+    }
 }
 
 impl<T: DijkstraExt> FromStr for City<T> {
@@ -272,4 +344,47 @@ mod tests {
         let c = city!(UltraCrucible, EXAMPLE_MAP);
         assert_eq!(c.traverse(), 94);
     }
+
+    #[test]
+    fn city_traverse_path_matches_traverse_cost() {
+        let c = city!();
+        let (cost, path) = c.traverse_path();
+
+        assert_eq!(cost, c.traverse());
+        assert_eq!(path.first(), Some(&Coordinate::new(0, 0)));
+        assert_eq!(path.last(), Some(&c.bottom_right()));
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn city_to_petgraph_matches_traverse_cost() {
+        use petgraph::algo::dijkstra;
+
+        let c = city!();
+        let (graph, indices) = c.to_petgraph();
+        let costs = dijkstra(&graph, indices[&City::<Crucible>::START], None, |e| {
+            *e.weight()
+        });
+
+        let goal_cost = indices
+            .iter()
+            .filter(|(node, _)| node.pos == c.bottom_right() && Crucible::is_done(node))
+            .filter_map(|(_, &idx)| costs.get(&idx))
+            .min()
+            .copied()
+            .unwrap();
+
+        assert_eq!(goal_cost, c.traverse());
+    }
+
+    #[test]
+    fn city_render_path_marks_visited_cells() {
+        let c = city!();
+        let (_, path) = c.traverse_path();
+        let rendered = c.render_path(&path);
+
+        let hashes = rendered.chars().filter(|&ch| ch == '#').count();
+        assert_eq!(hashes, path.len());
+        assert_eq!(rendered.lines().count(), c.grid.height());
+    }
 }
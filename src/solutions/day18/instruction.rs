@@ -171,19 +171,12 @@ impl Decoder for Hex {
 /// `A = I + (B - 2)/2`, then we can substitue our perimeter length `L + 4`
 /// (accounting for 4 outer corners) for `B`.
 ///
-/// This gives us `A = I + (L + 4 - 2)/2 = I + L/2 + 1`. This makes our fold
-/// implementation quite simple:
-///
-/// ```txt
-/// fold(instructions): initial=(area=0, perim=0, pos=(0,0))
-///   - next_pos <- simulate instruction(last_pos)
-///   - next_area <- last_area + shoelace(last_pos, next_pos)
-///   - next perim <- last_perim + abs(next_pos - last_pos)
-///
-/// --> returns (area, perim, pos)
-/// ```
-///
-/// Thus the final value is `(abs(area) + pos) / 2 + 1`.
+/// This gives us `A = I + (L + 4 - 2)/2 = I + L/2 + 1`. Rather than bury all
+/// of this in one `fold`, the pieces are split into their own building
+/// blocks: [`vertices`] walks the instructions into polygon corners,
+/// [`signed_area`] and [`boundary_points`] each reduce those corners to a
+/// single Pick's-theorem term, and [`interior_points`] combines them to get
+/// `I`. `capacity` itself is just `I + B`.
 ///
 /// ### Sources
 ///
@@ -196,20 +189,71 @@ pub fn capacity<'a, It>(it: It) -> usize
 where
     It: Iterator<Item = &'a Instruction>,
 {
-    // fold value has the form (area, perim, x, y)
-    let (a, p, _, _) = it.fold((0, 0, 0, 0), |(a, p, x, y), inst| {
-        let (x1, y1) = inst.simulate(x, y);
-        let a_next = a + ((x * y1) - (y * x1));
-        let p_next = p + (x1 - x).abs() + (y1 - y).abs();
-        (a_next, p_next, x1, y1)
-    });
-
-    let value = (a.abs() + p) / 2 + 1;
+    let verts = vertices(it);
+    let value = interior_points(&verts) + boundary_points(&verts);
     assert!(value >= 0);
 
     value as usize
 }
 
+/// Traces the ordered polygon corners dug by the given instructions.
+///
+/// The trench is assumed to start (and, since the instructions form a loop,
+/// end) at `(0, 0)`.
+pub fn vertices<'a, It>(it: It) -> Vec<(i64, i64)>
+where
+    It: Iterator<Item = &'a Instruction>,
+{
+    let mut pos = (0, 0);
+    let mut verts = vec![pos];
+    for inst in it {
+        pos = inst.simulate(pos.0, pos.1);
+        verts.push(pos);
+    }
+
+    verts
+}
+
+/// Computes the raw [shoelace sum] for a closed polygon's `vertices`.
+///
+/// This is twice the enclosed area; its sign reveals the winding order of
+/// `vertices`. Since `y` grows southward, it is positive for a loop that runs
+/// clockwise as seen from above and negative for a counter-clockwise one.
+///
+/// [shoelace sum]: <https://en.wikipedia.org/wiki/Shoelace_formula>
+pub fn signed_area(vertices: &[(i64, i64)]) -> i64 {
+    vertices
+        .windows(2)
+        .map(|w| {
+            let (x0, y0) = w[0];
+            let (x1, y1) = w[1];
+            (x0 * y1) - (y0 * x1)
+        })
+        .sum()
+}
+
+/// Counts the boundary points (`B` in Pick's theorem) traced by `vertices`,
+/// i.e. the perimeter length in unit steps.
+pub fn boundary_points(vertices: &[(i64, i64)]) -> i64 {
+    vertices
+        .windows(2)
+        .map(|w| {
+            let (x0, y0) = w[0];
+            let (x1, y1) = w[1];
+            (x1 - x0).abs() + (y1 - y0).abs()
+        })
+        .sum()
+}
+
+/// Counts the interior points (`I` in Pick's theorem) enclosed by `vertices`,
+/// i.e. `A = I + B/2 - 1` solved for `I`.
+pub fn interior_points(vertices: &[(i64, i64)]) -> i64 {
+    let area = signed_area(vertices).abs();
+    let b = boundary_points(vertices);
+
+    (area - b) / 2 + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,4 +386,16 @@ mod tests {
         let i = Instruction::decode_many(EXAMPLE_DATA.lines(), Hex).unwrap();
         assert_eq!(capacity(i.iter()), 952408144115);
     }
+
+    #[test]
+    fn geometry_building_blocks() {
+        let i = Instruction::decode_many(EXAMPLE_DATA.lines(), Standard).unwrap();
+        let verts = vertices(i.iter());
+
+        assert_eq!(verts.first(), verts.last());
+        assert_eq!(boundary_points(&verts), 38);
+        assert_eq!(signed_area(&verts).abs(), 84);
+        assert_eq!(interior_points(&verts), 24);
+        assert_eq!(interior_points(&verts) + boundary_points(&verts), 62);
+    }
 }
@@ -82,7 +82,40 @@ use std::path::Path;
 
 use anyhow::Result;
 
-pub use self::instruction::{capacity, Decoder, Hex, Instruction, Standard};
+pub use self::instruction::{
+    boundary_points, capacity, interior_points, signed_area, vertices, Decoder, Hex, Instruction,
+    Standard,
+};
+use crate::solver::Solver;
+
+/// [`Solver`] implementation for this day.
+///
+/// Both decodings of the dig plan (via [`Standard`] and [`Hex`]) are
+/// computed up front in [`Solver::parse`], so [`Solver::part1`]/[`Solver::part2`]
+/// are just a lagoon capacity calculation away from the answer.
+pub struct Day18 {
+    standard: Vec<Instruction>,
+    hex: Vec<Instruction>,
+}
+
+impl Solver for Day18 {
+    const DAY: i32 = 18;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            standard: Instruction::decode_many(input.lines(), Standard)?,
+            hex: Instruction::decode_many(input.lines(), Hex)?,
+        })
+    }
+
+    fn part1(&self) -> String {
+        capacity(self.standard.iter()).to_string()
+    }
+
+    fn part2(&self) -> String {
+        capacity(self.hex.iter()).to_string()
+    }
+}
 
 fn part1(s: &str) -> Result<()> {
     let instructions = Instruction::decode_many(s.lines(), Standard)?;
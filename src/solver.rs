@@ -0,0 +1,104 @@
+//! A common entry point for day solutions.
+//!
+//! Historically each day exposed its own bespoke `parse`/`part1`/`part2`
+//! functions with no shared shape, so running a given day/part required
+//! hand-wiring a new match arm into `main`. Implementing [`Solver`] instead
+//! lets a day be parsed once and queried for both parts' answers, and
+//! registering it in [`REGISTRY`] is all that's needed to run it from the
+//! CLI with per-part timing.
+
+use std::{path::Path, time::Instant};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{cli::OutputFormat, solutions};
+
+/// A day's solution, parsed once from its input and queried for both parts'
+/// answers.
+pub trait Solver: Sized {
+    /// This solver's day number, for labeling output.
+    const DAY: i32;
+
+    /// Parses this day's input into a solver instance.
+    fn parse(input: &str) -> Result<Self>;
+
+    /// Returns the answer to part 1.
+    fn part1(&self) -> String;
+
+    /// Returns the answer to part 2.
+    fn part2(&self) -> String;
+
+    /// Parses `input` and returns both parts' answers, without timing or
+    /// printing. An entry point for callers that already have the input in
+    /// memory.
+    fn solve_str(input: &str) -> Result<(String, String)> {
+        let solver = Self::parse(input)?;
+        Ok((solver.part1(), solver.part2()))
+    }
+}
+
+/// A single part's answer, in a shape that serializes cleanly for
+/// `--format json` consumers (`jq`, nushell, etc).
+#[derive(Debug, Serialize)]
+pub struct Answer {
+    pub day: i32,
+    pub part: u8,
+    pub value: String,
+    pub elapsed_ms: u128,
+}
+
+/// A type-erased entry point for a [`Solver`], suitable for storing in
+/// [`REGISTRY`].
+pub type Runner = fn(&Path, OutputFormat) -> Result<()>;
+
+/// Parses a [`Solver`] and times each part independently, writing the
+/// results to stdout in the requested `format`.
+pub fn run<S: Solver>(input: &Path, format: OutputFormat) -> Result<()> {
+    let contents = std::fs::read_to_string(input)?;
+    let solver = S::parse(&contents)?;
+
+    let start = Instant::now();
+    let value = solver.part1();
+    emit(Answer { day: S::DAY, part: 1, value, elapsed_ms: start.elapsed().as_millis() }, format);
+
+    let start = Instant::now();
+    let value = solver.part2();
+    emit(Answer { day: S::DAY, part: 2, value, elapsed_ms: start.elapsed().as_millis() }, format);
+
+    Ok(())
+}
+
+/// Writes a single part's [`Answer`] in the requested `format`.
+fn emit(answer: Answer, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => println!(
+            "Part {}: {} ({})",
+            answer.part,
+            answer.value,
+            humantime::format_duration(std::time::Duration::from_millis(answer.elapsed_ms as u64))
+        ),
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&answer).expect("Answer always serializes"))
+        }
+    }
+}
+
+/// Maps a day number to its [`Runner`].
+///
+/// Not every day is registered here yet -- days still using their own
+/// `exec`/`part1`/`part2` functions are dispatched directly from `main`. Add
+/// a day here once it implements [`Solver`].
+pub static REGISTRY: &[(i32, Runner)] = &[
+    (3, run::<solutions::day03::Day03>),
+    (4, run::<solutions::day04::Day04>),
+    (18, run::<solutions::day18::Day18>),
+];
+
+/// Looks up and runs the [`Runner`] registered for `day`, if one exists.
+pub fn run_day(day: i32, input: &Path, format: OutputFormat) -> Option<Result<()>> {
+    REGISTRY
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, runner)| runner(input, format))
+}